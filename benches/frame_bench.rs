@@ -1,5 +1,5 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use pulse::{Frame, Rect};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use pulse::{backend::TerminalBackend, ColorDepth, Frame, Rect};
 
 fn bench_frame_full_redraw(c: &mut Criterion) {
     c.bench_function("frame/full_redraw_120x40", |b| {
@@ -51,10 +51,49 @@ fn bench_frame_nested_clipping(c: &mut Criterion) {
     });
 }
 
+fn emitted_bytes(frame: &Frame) -> u64 {
+    let mut backend = TerminalBackend::with_writer(Vec::new(), frame.width(), frame.height())
+        .with_color_depth(ColorDepth::TrueColor);
+    backend.render(frame).expect("render to memory succeeds");
+    backend.writer().len() as u64
+}
+
+fn bench_frame_backend_output(c: &mut Criterion) {
+    let mut full = Frame::new(200, 60);
+    let line = "x".repeat(200);
+    for y in 0..full.height() {
+        full.print(0, y, &line);
+    }
+
+    let mut sparse = Frame::new(200, 60);
+    for y in (0..sparse.height()).step_by(3) {
+        sparse.print(y, y, "status: 1234567890");
+    }
+
+    let mut group = c.benchmark_group("frame/backend");
+    for (name, frame) in [
+        ("full_repaint_200x60", &full),
+        ("sparse_update_200x60", &sparse),
+    ] {
+        group.throughput(Throughput::Bytes(emitted_bytes(frame)));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut backend =
+                    TerminalBackend::with_writer(Vec::new(), frame.width(), frame.height())
+                        .with_color_depth(ColorDepth::TrueColor);
+                backend.render(frame).expect("render to memory succeeds");
+                black_box(backend.writer().len());
+            });
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     frame_benches,
     bench_frame_full_redraw,
     bench_frame_partial_update,
-    bench_frame_nested_clipping
+    bench_frame_nested_clipping,
+    bench_frame_backend_output
 );
 criterion_main!(frame_benches);
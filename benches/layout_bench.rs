@@ -11,7 +11,7 @@ fn dashboard_layout() -> LayoutNode {
                 LayoutNode::leaf("header").with_padding(Padding::symmetric(1, 2)),
             ),
             Slot::new(
-                Constraint::Fill,
+                Constraint::Fill(1),
                 LayoutNode::split(
                     "body",
                     Direction::Horizontal,
@@ -21,13 +21,13 @@ fn dashboard_layout() -> LayoutNode {
                             LayoutNode::leaf("sidebar").with_padding(Padding::all(1)),
                         ),
                         Slot::new(
-                            Constraint::Fill,
+                            Constraint::Fill(1),
                             LayoutNode::split(
                                 "content_stack",
                                 Direction::Vertical,
                                 [
                                     Slot::new(Constraint::Percent(70), LayoutNode::leaf("main")),
-                                    Slot::new(Constraint::Fill, LayoutNode::leaf("log")),
+                                    Slot::new(Constraint::Fill(1), LayoutNode::leaf("log")),
                                 ],
                             )
                             .with_padding(Padding::all(1)),
@@ -77,7 +77,30 @@ fn bench_layout_percent_heavy(c: &mut Criterion) {
                 Slot::new(Constraint::Percent(20), LayoutNode::leaf("b")),
                 Slot::new(Constraint::Percent(30), LayoutNode::leaf("c")),
                 Slot::new(Constraint::Percent(40), LayoutNode::leaf("d")),
-                Slot::new(Constraint::Fill, LayoutNode::leaf("tail")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("tail")),
+            ],
+        );
+
+        b.iter(|| {
+            let resolved = layout.resolve(Rect::new(0, 0, 200, 60));
+            black_box(resolved.zones().len());
+        });
+    });
+}
+
+fn bench_layout_bounded_mix(c: &mut Criterion) {
+    c.bench_function("layout/bounded_mix_200x60", |b| {
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Horizontal,
+            [
+                Slot::new(Constraint::Percent(30), LayoutNode::leaf("sidebar"))
+                    .min(20)
+                    .max(50),
+                Slot::new(Constraint::Ratio(1, 4), LayoutNode::leaf("detail")),
+                Slot::new(Constraint::Min(10), LayoutNode::leaf("main")),
+                Slot::new(Constraint::Max(12), LayoutNode::leaf("gutter")),
+                Slot::new(Constraint::Fill(2), LayoutNode::leaf("tail")),
             ],
         );
 
@@ -92,6 +115,7 @@ criterion_group!(
     layout_benches,
     bench_layout_resolve_desktop,
     bench_layout_resolve_small_terminal,
-    bench_layout_percent_heavy
+    bench_layout_percent_heavy,
+    bench_layout_bounded_mix
 );
 criterion_main!(layout_benches);
@@ -1,6 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use pulse::{
-    apply_input_edit, Color, Frame, Input, InputEdit, Paragraph, Rect, StatusBar, Style, WrapMode,
+    apply_input_edit, backend::TerminalBackend, Color, ColorDepth, Frame, Input, InputEdit,
+    Paragraph, Rect, StatusBar, Style, WrapMode,
 };
 
 fn bench_style_full_repaint(c: &mut Criterion) {
@@ -89,11 +90,57 @@ fn bench_style_input_edit_cycle(c: &mut Criterion) {
     });
 }
 
+fn bench_style_backend_output(c: &mut Criterion) {
+    let line = "x".repeat(120);
+    let style_a = Style::new().fg(Color::Ansi(45)).bg(Color::Ansi(17));
+    let style_b = Style::new()
+        .fg(Color::Rgb(230, 200, 120))
+        .bg(Color::Rgb(52, 20, 20));
+
+    let mut striped = Frame::new(120, 40);
+    for y in 0..striped.height() {
+        let style = if y % 2 == 0 { style_a } else { style_b };
+        striped.print_styled(0, y, &line, style);
+    }
+
+    let mut checkered = Frame::new(120, 40);
+    for y in 0..checkered.height() {
+        for x in 0..checkered.width() {
+            let style = if (x + y) % 2 == 0 { style_a } else { style_b };
+            checkered.print_styled(x, y, "x", style);
+        }
+    }
+
+    let mut group = c.benchmark_group("style/backend");
+    for (name, frame, depth) in [
+        ("striped_truecolor_120x40", &striped, ColorDepth::TrueColor),
+        ("striped_ansi256_120x40", &striped, ColorDepth::Ansi256),
+        (
+            "checkered_truecolor_120x40",
+            &checkered,
+            ColorDepth::TrueColor,
+        ),
+    ] {
+        let render = || {
+            let mut backend =
+                TerminalBackend::with_writer(Vec::new(), frame.width(), frame.height())
+                    .with_color_depth(depth);
+            backend.render(frame).expect("render to memory succeeds");
+            backend.into_writer()
+        };
+
+        group.throughput(Throughput::Bytes(render().len() as u64));
+        group.bench_function(name, |b| b.iter(|| black_box(render().len())));
+    }
+    group.finish();
+}
+
 criterion_group!(
     style_benches,
     bench_style_full_repaint,
     bench_style_only_diff,
     bench_style_mixed_widgets_frame,
-    bench_style_input_edit_cycle
+    bench_style_input_edit_cycle,
+    bench_style_backend_output
 );
 criterion_main!(style_benches);
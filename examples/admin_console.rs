@@ -2,8 +2,8 @@ use std::io;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use pulse::{
-    run, App, Color, Command, Constraint, Direction, Frame, LayoutNode, List, ListStyle, Padding,
-    Panel, PanelStyle, Rect, Slot, StatusBar, StatusBarStyle, Style, Text, Theme,
+    run, App, Color, Command, Constraint, Frame, GridCell, LayoutNode, List, ListStyle, Padding,
+    Panel, PanelStyle, Rect, StatusBar, StatusBarStyle, Style, Text, Theme,
 };
 
 const NAV_ITEMS: [&str; 7] = [
@@ -111,35 +111,37 @@ impl App for AdminConsole {
 }
 
 fn build_layout() -> LayoutNode {
-    LayoutNode::split(
+    LayoutNode::grid(
         "root",
-        Direction::Vertical,
         [
-            Slot::new(
-                Constraint::Fixed(3),
+            Constraint::Fixed(3),
+            Constraint::Fill(1),
+            Constraint::Fixed(1),
+        ],
+        [Constraint::Percent(28), Constraint::Fill(1)],
+        [
+            GridCell::new(
+                0,
+                0,
                 LayoutNode::leaf("header").with_padding(Padding::symmetric(1, 2)),
+            )
+            .column_span(2),
+            GridCell::new(
+                1,
+                0,
+                LayoutNode::leaf("sidebar").with_padding(Padding::all(1)),
             ),
-            Slot::new(
-                Constraint::Fill,
-                LayoutNode::split(
-                    "body",
-                    Direction::Horizontal,
-                    [
-                        Slot::new(
-                            Constraint::Percent(28),
-                            LayoutNode::leaf("sidebar").with_padding(Padding::all(1)),
-                        ),
-                        Slot::new(
-                            Constraint::Fill,
-                            LayoutNode::leaf("content").with_padding(Padding::all(1)),
-                        ),
-                    ],
-                ),
+            GridCell::new(
+                1,
+                1,
+                LayoutNode::leaf("content").with_padding(Padding::all(1)),
             ),
-            Slot::new(
-                Constraint::Fixed(1),
+            GridCell::new(
+                2,
+                0,
                 LayoutNode::leaf("footer").with_padding(Padding::symmetric(0, 2)),
-            ),
+            )
+            .column_span(2),
         ],
     )
 }
@@ -248,7 +248,6 @@ impl App for FormDemo {
                             .style(input.base)
                             .focus_style(input.focus)
                             .placeholder_style(input.placeholder)
-                            .cursor_style(input.cursor)
                             .render(frame, area);
                     });
 
@@ -166,7 +166,7 @@ fn build_layout() -> LayoutNode {
                 LayoutNode::leaf("header").with_padding(Padding::symmetric(1, 2)),
             ),
             Slot::new(
-                Constraint::Fill,
+                Constraint::Fill(1),
                 LayoutNode::split(
                     "body",
                     Direction::Horizontal,
@@ -176,7 +176,7 @@ fn build_layout() -> LayoutNode {
                             LayoutNode::leaf("sidebar").with_padding(Padding::all(1)),
                         ),
                         Slot::new(
-                            Constraint::Fill,
+                            Constraint::Fill(1),
                             LayoutNode::leaf("content").with_padding(Padding::all(1)),
                         ),
                     ],
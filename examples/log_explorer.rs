@@ -137,7 +137,7 @@ fn build_layout() -> LayoutNode {
                 LayoutNode::leaf("filters").with_padding(Padding::symmetric(1, 2)),
             ),
             Slot::new(
-                Constraint::Fill,
+                Constraint::Fill(1),
                 LayoutNode::split(
                     "body",
                     Direction::Horizontal,
@@ -147,7 +147,7 @@ fn build_layout() -> LayoutNode {
                             LayoutNode::leaf("sources").with_padding(Padding::all(1)),
                         ),
                         Slot::new(
-                            Constraint::Fill,
+                            Constraint::Fill(1),
                             LayoutNode::leaf("logs").with_padding(Padding::all(1)),
                         ),
                     ],
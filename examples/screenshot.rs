@@ -0,0 +1,82 @@
+use std::{env, fs, io, path::PathBuf};
+
+use pulse::{
+    Block, Color, Frame, List, Modifier, Padding, Paragraph, Rect, StatusBar, Style, WriteMode,
+};
+
+fn render(frame: &mut Frame) {
+    let area = Rect::new(0, 0, frame.width(), frame.height());
+    let (body, footer) = area.split_vertical(area.height.saturating_sub(1));
+    let (nav, content) = body.split_horizontal(22);
+
+    let block = Block::new()
+        .title("Navigation")
+        .body_style(Style::new().bg(Color::Rgb(20, 28, 52)))
+        .border_style(Style::new().fg(Color::Ansi(39)))
+        .padding(Padding::symmetric(0, 1));
+    block.render(frame, nav);
+    List::new(["Overview", "Metrics", "Logs"])
+        .selected(1)
+        .item_style(Style::new().fg(Color::Ansi(252)).bg(Color::Rgb(20, 28, 52)))
+        .selected_style(Style::new().fg(Color::Ansi(16)).bg(Color::Ansi(39)))
+        .render(frame, block.inner_area(nav));
+
+    let panel = Block::new()
+        .title("Diff")
+        .body_style(Style::new().bg(Color::Rgb(22, 32, 56)))
+        .border_style(Style::new().fg(Color::Ansi(39)));
+    panel.render(frame, content);
+    let inner = panel.inner_area(content);
+    frame.with_write_mode(WriteMode::Patch, |frame| {
+        frame.render_in(inner, |frame| {
+            frame.print_styled(
+                1,
+                0,
+                "- retries = 3",
+                Style::new()
+                    .fg(Color::Red)
+                    .modifier(Modifier::Strikethrough),
+            );
+            frame.print_styled(1, 1, "+ retries = 5", Style::new().fg(Color::Green));
+            frame.print_styled(
+                1,
+                3,
+                "recieve",
+                Style::new()
+                    .underline_color(Color::BrightRed)
+                    .modifier(Modifier::CurlyUnderline),
+            );
+        });
+        Paragraph::new("Rendered headlessly and exported as ANSI, HTML, and SVG.")
+            .style(Style::new().fg(Color::Ansi(250)))
+            .render(frame, Rect::new(inner.x + 1, inner.y + 5, inner.width, 2));
+    });
+
+    StatusBar::new()
+        .left("pulse screenshot")
+        .right("ansi | html | svg")
+        .style(Style::new().bg(Color::Rgb(28, 28, 28)).fg(Color::Ansi(250)))
+        .render(frame, footer);
+}
+
+fn main() -> io::Result<()> {
+    let out_dir = env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(env::temp_dir);
+
+    let mut frame = Frame::new(72, 12);
+    render(&mut frame);
+
+    for (name, contents) in [
+        ("pulse-screenshot.ans", frame.to_ansi_string()),
+        ("pulse-screenshot.html", frame.to_html()),
+        ("pulse-screenshot.svg", frame.to_svg()),
+    ] {
+        let path = out_dir.join(name);
+        fs::write(&path, contents)?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
@@ -35,7 +35,6 @@ struct Palette {
     input_bg: Style,
     input_text: Style,
     input_placeholder: Style,
-    input_cursor: Style,
     input_focus: Style,
 }
 
@@ -114,11 +113,6 @@ impl Palette {
                 "input.placeholder",
                 Style::new().fg(Color::Ansi(244)),
             ),
-            input_cursor: style_from(
-                theme,
-                "input.cursor",
-                Style::new().bg(Color::Ansi(39)).fg(Color::Ansi(16)),
-            ),
             input_focus: style_from(
                 theme,
                 "input.focus",
@@ -281,7 +275,6 @@ impl App for SettingsApp {
                 .style(palette.input_text)
                 .focus_style(palette.input_focus)
                 .placeholder_style(palette.input_placeholder)
-                .cursor_style(palette.input_cursor)
                 .margin(Padding {
                     top: 3,
                     right: 1,
@@ -372,7 +365,7 @@ fn build_layout() -> LayoutNode {
                 LayoutNode::leaf("title").with_padding(Padding::symmetric(1, 2)),
             ),
             Slot::new(
-                Constraint::Fill,
+                Constraint::Fill(1),
                 LayoutNode::split(
                     "body",
                     Direction::Horizontal,
@@ -382,7 +375,7 @@ fn build_layout() -> LayoutNode {
                             LayoutNode::leaf("categories").with_padding(Padding::all(1)),
                         ),
                         Slot::new(
-                            Constraint::Fill,
+                            Constraint::Fill(1),
                             LayoutNode::leaf("details").with_padding(Padding::all(1)),
                         ),
                     ],
@@ -50,7 +50,7 @@ impl App for TableDemo {
             TableColumn::new("Service", Constraint::Fixed(18)).align(Alignment::Left),
             TableColumn::new("Latency", Constraint::Fixed(10)).align(Alignment::Center),
             TableColumn::new("Error%", Constraint::Fixed(8)).align(Alignment::Right),
-            TableColumn::new("Status", Constraint::Fill).align(Alignment::Left),
+            TableColumn::new("Status", Constraint::Fill(1)).align(Alignment::Left),
         ];
 
         Table::new(columns, self.rows.clone())
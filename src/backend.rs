@@ -1,113 +1,757 @@
-use std::io::{self, stdout, Stdout, Write};
+use std::{
+    env,
+    io::{self, stdout, Stdout, Write},
+};
 
 use crossterm::{
-    cursor::MoveTo,
+    cursor::{Hide, MoveTo, SetCursorStyle, Show},
     queue,
     style::{
         Attribute, Color as CrosstermColor, Print, ResetColor, SetAttribute, SetBackgroundColor,
-        SetForegroundColor,
+        SetForegroundColor, SetUnderlineColor,
     },
-    terminal::{Clear, ClearType},
+    terminal::{BeginSynchronizedUpdate, Clear, ClearType, EndSynchronizedUpdate},
 };
 
-use crate::{Color, Frame, Modifier, Style};
+use crate::{Color, CursorShape, Frame, Modifier, Style};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    Monochrome,
+}
+
+impl ColorDepth {
+    pub fn detect() -> Self {
+        let no_color = env::var("NO_COLOR").ok();
+        let colorterm = env::var("COLORTERM").ok();
+        let term = env::var("TERM").ok();
+        Self::from_env_values(no_color.as_deref(), colorterm.as_deref(), term.as_deref())
+    }
+
+    fn from_env_values(
+        no_color: Option<&str>,
+        colorterm: Option<&str>,
+        term: Option<&str>,
+    ) -> Self {
+        if no_color.is_some_and(|value| !value.is_empty()) {
+            return ColorDepth::Monochrome;
+        }
+
+        let colorterm = colorterm.unwrap_or("").to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorDepth::TrueColor;
+        }
+
+        let term = term.unwrap_or("").to_ascii_lowercase();
+        if term == "dumb" {
+            ColorDepth::Monochrome
+        } else if term.contains("truecolor") || term.contains("24bit") || term.contains("direct") {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
+pub fn synchronized_output_supported() -> bool {
+    let term = env::var("TERM").ok();
+    let term_program = env::var("TERM_PROGRAM").ok();
+    let wt_session = env::var("WT_SESSION").ok();
+    synchronized_output_from_env_values(
+        term.as_deref(),
+        term_program.as_deref(),
+        wt_session.as_deref(),
+    )
+}
+
+fn synchronized_output_from_env_values(
+    term: Option<&str>,
+    term_program: Option<&str>,
+    wt_session: Option<&str>,
+) -> bool {
+    if wt_session.is_some() {
+        return true;
+    }
 
-pub struct TerminalBackend {
-    stdout: Stdout,
+    let term_program = term_program.unwrap_or("").to_ascii_lowercase();
+    if ["wezterm", "iterm.app", "ghostty", "vscode", "contour"]
+        .iter()
+        .any(|known| term_program == *known)
+    {
+        return true;
+    }
+
+    let term = term.unwrap_or("").to_ascii_lowercase();
+    [
+        "kitty",
+        "alacritty",
+        "foot",
+        "wezterm",
+        "ghostty",
+        "contour",
+    ]
+    .iter()
+    .any(|known| term.contains(known))
+}
+
+pub struct TerminalBackend<W: Write = Stdout> {
+    out: W,
     previous: Frame,
     active_style: Style,
+    color_depth: ColorDepth,
+    synchronized_output: bool,
+    cursor_position: Option<(u16, u16)>,
+    cursor_visible: bool,
+    cursor_shape: Option<(CursorShape, bool)>,
 }
 
 impl TerminalBackend {
     pub fn new(width: u16, height: u16) -> Self {
+        Self::with_writer(stdout(), width, height)
+    }
+}
+
+impl<W: Write> TerminalBackend<W> {
+    pub fn with_writer(out: W, width: u16, height: u16) -> Self {
         Self {
-            stdout: stdout(),
+            out,
             previous: Frame::new(width, height),
             active_style: Style::default(),
+            color_depth: ColorDepth::detect(),
+            synchronized_output: synchronized_output_supported(),
+            cursor_position: None,
+            cursor_visible: false,
+            cursor_shape: None,
         }
     }
 
+    pub fn with_synchronized_output(mut self, enabled: bool) -> Self {
+        self.synchronized_output = enabled;
+        self
+    }
+
+    pub fn synchronized_output(&self) -> bool {
+        self.synchronized_output
+    }
+
+    pub fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    pub fn writer(&self) -> &W {
+        &self.out
+    }
+
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    pub fn into_writer(self) -> W {
+        self.out
+    }
+
     pub fn render(&mut self, current: &Frame) -> io::Result<()> {
+        if self.synchronized_output {
+            queue!(self.out, BeginSynchronizedUpdate)?;
+        }
+
         if self.previous.width() != current.width() || self.previous.height() != current.height() {
-            queue!(self.stdout, Clear(ClearType::All))?;
+            queue!(self.out, Clear(ClearType::All))?;
             self.previous = Frame::new(current.width(), current.height());
             self.active_style = Style::default();
+            self.cursor_position = None;
         }
 
         let width = current.width() as usize;
-        for idx in 0..current.cells().len() {
-            let prev_cell = self.previous.cells()[idx];
-            let curr_cell = current.cells()[idx];
-            if prev_cell == curr_cell {
-                continue;
-            }
+        let previous = self.previous.cells();
+        let cells = current.cells();
+        let mut cursor = self.cursor_position;
+        let mut run = String::new();
+
+        for (y, row) in cells.chunks(width.max(1)).enumerate() {
+            let row_start = y * width;
+            let mut x = 0;
+            while x < row.len() {
+                if previous[row_start + x] == row[x] {
+                    x += 1;
+                    continue;
+                }
 
-            let x = (idx % width) as u16;
-            let y = (idx / width) as u16;
-            self.apply_style(curr_cell.style)?;
-            queue!(self.stdout, MoveTo(x, y), Print(curr_cell.ch))?;
+                let start = x;
+                let style = row[x].style;
+                let mut narrow = true;
+                run.clear();
+                while x < row.len() && previous[row_start + x] != row[x] && row[x].style == style {
+                    run.push(row[x].ch);
+                    x += 1;
+                    if !is_narrow(row[x - 1].ch) {
+                        narrow = false;
+                        break;
+                    }
+                }
+
+                let position = (start as u16, y as u16);
+                if cursor != Some(position) {
+                    queue!(self.out, MoveTo(position.0, position.1))?;
+                }
+                apply_style(
+                    &mut self.out,
+                    &mut self.active_style,
+                    self.color_depth,
+                    style,
+                )?;
+                queue!(self.out, Print(&run))?;
+                cursor = narrow.then_some((x as u16, y as u16));
+            }
         }
 
-        queue!(self.stdout, ResetColor, SetAttribute(Attribute::Reset))?;
+        queue!(self.out, ResetColor, SetAttribute(Attribute::Reset))?;
         self.active_style = Style::default();
-        self.stdout.flush()?;
+
+        match current.cursor() {
+            Some(target) => {
+                if cursor != Some((target.x, target.y)) {
+                    queue!(self.out, MoveTo(target.x, target.y))?;
+                    cursor = Some((target.x, target.y));
+                }
+                let shape = (target.shape, target.blinking);
+                if self.cursor_shape != Some(shape) {
+                    queue!(self.out, cursor_style(shape.0, shape.1))?;
+                    self.cursor_shape = Some(shape);
+                }
+                if !self.cursor_visible {
+                    queue!(self.out, Show)?;
+                    self.cursor_visible = true;
+                }
+            }
+            None => {
+                if self.cursor_visible {
+                    queue!(self.out, Hide)?;
+                    self.cursor_visible = false;
+                }
+            }
+        }
+        self.cursor_position = cursor;
+
+        if self.synchronized_output {
+            queue!(self.out, EndSynchronizedUpdate)?;
+        }
+        self.out.flush()?;
         self.previous.sync_from(current);
         Ok(())
     }
+}
+
+fn is_narrow(ch: char) -> bool {
+    matches!(
+        ch,
+        ' '..='~'
+            | '\u{a0}'..='\u{2ff}'
+            | '\u{2010}'..='\u{205e}'
+            | '\u{2190}'..='\u{21ff}'
+            | '\u{2500}'..='\u{25ff}'
+    )
+}
+
+fn cursor_style(shape: CursorShape, blinking: bool) -> SetCursorStyle {
+    match (shape, blinking) {
+        (CursorShape::Block, true) => SetCursorStyle::BlinkingBlock,
+        (CursorShape::Block, false) => SetCursorStyle::SteadyBlock,
+        (CursorShape::Bar, true) => SetCursorStyle::BlinkingBar,
+        (CursorShape::Bar, false) => SetCursorStyle::SteadyBar,
+        (CursorShape::Underline, true) => SetCursorStyle::BlinkingUnderScore,
+        (CursorShape::Underline, false) => SetCursorStyle::SteadyUnderScore,
+    }
+}
 
-    fn apply_style(&mut self, style: Style) -> io::Result<()> {
-        if self.active_style == style {
-            return Ok(());
-        }
+pub(crate) fn apply_style<W: Write>(
+    out: &mut W,
+    active_style: &mut Style,
+    depth: ColorDepth,
+    style: Style,
+) -> io::Result<()> {
+    if *active_style == style {
+        return Ok(());
+    }
 
-        queue!(self.stdout, SetAttribute(Attribute::Reset))?;
-        queue!(
-            self.stdout,
-            SetForegroundColor(map_color(style.fg.unwrap_or(Color::Default))),
-            SetBackgroundColor(map_color(style.bg.unwrap_or(Color::Default)))
-        )?;
+    queue!(out, SetAttribute(Attribute::Reset))?;
+    queue!(
+        out,
+        SetForegroundColor(map_color(style.fg.unwrap_or(Color::Default), depth)),
+        SetBackgroundColor(map_color(style.bg.unwrap_or(Color::Default), depth))
+    )?;
 
-        if style.modifiers.contains(Modifier::Bold) {
-            queue!(self.stdout, SetAttribute(Attribute::Bold))?;
+    if let Some(color) = style.underline_color {
+        queue!(out, SetUnderlineColor(map_color(color, depth)))?;
+    }
+
+    let mut modifiers = style.modifiers;
+    if depth == ColorDepth::Monochrome && needs_monochrome_reverse(style) {
+        modifiers = modifiers.insert(Modifier::Reverse);
+    }
+
+    for (modifier, attribute) in MODIFIER_ATTRIBUTES {
+        if modifiers.contains(modifier) {
+            queue!(out, SetAttribute(attribute))?;
         }
-        if style.modifiers.contains(Modifier::Dim) {
-            queue!(self.stdout, SetAttribute(Attribute::Dim))?;
+    }
+
+    *active_style = style;
+    Ok(())
+}
+
+const MODIFIER_ATTRIBUTES: [(Modifier, Attribute); 10] = [
+    (Modifier::Bold, Attribute::Bold),
+    (Modifier::Dim, Attribute::Dim),
+    (Modifier::Italic, Attribute::Italic),
+    (Modifier::Underline, Attribute::Underlined),
+    (Modifier::Reverse, Attribute::Reverse),
+    (Modifier::Strikethrough, Attribute::CrossedOut),
+    (Modifier::Blink, Attribute::SlowBlink),
+    (Modifier::Hidden, Attribute::Hidden),
+    (Modifier::DoubleUnderline, Attribute::DoubleUnderlined),
+    (Modifier::CurlyUnderline, Attribute::Undercurled),
+];
+
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn map_color(color: Color, depth: ColorDepth) -> CrosstermColor {
+    match (color, depth) {
+        (Color::Default, _) | (_, ColorDepth::Monochrome) => CrosstermColor::Reset,
+        (Color::Ansi(value), ColorDepth::TrueColor | ColorDepth::Ansi256) => {
+            CrosstermColor::AnsiValue(value)
         }
-        if style.modifiers.contains(Modifier::Italic) {
-            queue!(self.stdout, SetAttribute(Attribute::Italic))?;
+        (Color::Ansi(value), ColorDepth::Ansi16) if value < 16 => CrosstermColor::AnsiValue(value),
+        (Color::Ansi(value), ColorDepth::Ansi16) => {
+            let (r, g, b) = ansi256_to_rgb(value);
+            CrosstermColor::AnsiValue(nearest_ansi16(r, g, b))
         }
-        if style.modifiers.contains(Modifier::Underline) {
-            queue!(self.stdout, SetAttribute(Attribute::Underlined))?;
+        (Color::Rgb(r, g, b), ColorDepth::TrueColor) => CrosstermColor::Rgb { r, g, b },
+        (Color::Rgb(r, g, b), ColorDepth::Ansi256) => {
+            CrosstermColor::AnsiValue(nearest_ansi256(r, g, b))
         }
-        if style.modifiers.contains(Modifier::Reverse) {
-            queue!(self.stdout, SetAttribute(Attribute::Reverse))?;
+        (Color::Rgb(r, g, b), ColorDepth::Ansi16) => {
+            CrosstermColor::AnsiValue(nearest_ansi16(r, g, b))
         }
-
-        self.active_style = style;
-        Ok(())
+        (named, _) => CrosstermColor::AnsiValue(named.named_index().unwrap_or(0)),
     }
 }
 
-fn map_color(color: Color) -> CrosstermColor {
+fn needs_monochrome_reverse(style: Style) -> bool {
+    let Some(bg) = style.bg.and_then(color_rgb) else {
+        return false;
+    };
+    let fg = style.fg.and_then(color_rgb).unwrap_or((192, 192, 192));
+
+    luminance(bg) > luminance(fg)
+}
+
+pub(crate) fn color_rgb(color: Color) -> Option<(u8, u8, u8)> {
     match color {
-        Color::Default => CrosstermColor::Reset,
-        Color::Ansi(value) => CrosstermColor::AnsiValue(value),
-        Color::Rgb(r, g, b) => CrosstermColor::Rgb { r, g, b },
+        Color::Default => None,
+        Color::Ansi(value) => Some(ansi256_to_rgb(value)),
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        named => named.named_index().map(ansi256_to_rgb),
     }
 }
 
+fn luminance((r, g, b): (u8, u8, u8)) -> u32 {
+    2126 * r as u32 + 7152 * g as u32 + 722 * b as u32
+}
+
+fn ansi256_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => ANSI16_PALETTE[value as usize],
+        16..=231 => {
+            let idx = value - 16;
+            (
+                CUBE_LEVELS[(idx / 36) as usize],
+                CUBE_LEVELS[(idx / 6 % 6) as usize],
+                CUBE_LEVELS[(idx % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let level = 8 + (value - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |channel: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (channel as i32 - **level as i32).abs())
+            .map(|(idx, _)| idx as u8)
+            .unwrap_or(0)
+    };
+    let cube = 16 + 36 * cube_index(r) + 6 * cube_index(g) + cube_index(b);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 232 + gray_step;
+
+    if distance((r, g, b), ansi256_to_rgb(gray)) < distance((r, g, b), ansi256_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    ANSI16_PALETTE
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, candidate)| distance((r, g, b), **candidate))
+        .map(|(idx, _)| idx as u8)
+        .unwrap_or(0)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{map_color, CrosstermColor};
-    use crate::Color;
+    use super::{
+        map_color, needs_monochrome_reverse, synchronized_output_from_env_values, Attribute,
+        ColorDepth, CrosstermColor, TerminalBackend, MODIFIER_ATTRIBUTES,
+    };
+    use crate::{Color, CursorShape, Frame, Modifier, Style};
+
+    fn render_to_string(backend: &mut TerminalBackend<Vec<u8>>, frame: &Frame) -> String {
+        backend.writer_mut().clear();
+        backend.render(frame).expect("render to memory succeeds");
+        String::from_utf8(backend.writer().clone()).expect("output is utf-8")
+    }
+
+    fn memory_backend(width: u16, height: u16) -> TerminalBackend<Vec<u8>> {
+        TerminalBackend::with_writer(Vec::new(), width, height)
+            .with_color_depth(ColorDepth::TrueColor)
+            .with_synchronized_output(false)
+    }
+
+    const BEGIN_SYNC: &str = "\x1b[?2026h";
+    const END_SYNC: &str = "\x1b[?2026l";
+
+    #[test]
+    fn synchronized_output_brackets_every_frame() {
+        let mut backend = memory_backend(4, 2).with_synchronized_output(true);
+        let mut frame = Frame::new(4, 2);
+
+        let mut outputs = vec![render_to_string(&mut backend, &frame)];
+        frame.print(0, 0, "ab");
+        outputs.push(render_to_string(&mut backend, &frame));
+        outputs.push(render_to_string(&mut backend, &Frame::new(3, 3)));
+
+        for output in outputs {
+            assert!(output.starts_with(BEGIN_SYNC), "{output:?}");
+            assert!(output.ends_with(END_SYNC), "{output:?}");
+            assert_eq!(output.matches(BEGIN_SYNC).count(), 1);
+            assert_eq!(output.matches(END_SYNC).count(), 1);
+        }
+    }
+
+    #[test]
+    fn synchronized_output_can_be_disabled() {
+        let mut backend = memory_backend(4, 1);
+        let mut frame = Frame::new(4, 1);
+        frame.print(0, 0, "ab");
+
+        let output = render_to_string(&mut backend, &frame);
+
+        assert!(!output.contains(BEGIN_SYNC));
+        assert!(!output.contains(END_SYNC));
+    }
+
+    #[test]
+    fn detects_synchronized_output_support_from_environment_values() {
+        let detect = synchronized_output_from_env_values;
+        assert!(detect(Some("xterm-kitty"), None, None));
+        assert!(detect(Some("xterm-256color"), Some("WezTerm"), None));
+        assert!(detect(Some("xterm-256color"), None, Some("abc")));
+        assert!(!detect(
+            Some("xterm-256color"),
+            Some("Apple_Terminal"),
+            None
+        ));
+        assert!(!detect(None, None, None));
+    }
+
+    #[test]
+    fn render_batches_adjacent_cells_into_one_print() {
+        let mut backend = memory_backend(6, 2);
+        let mut frame = Frame::new(6, 2);
+        frame.print(0, 0, "abc");
+
+        let output = render_to_string(&mut backend, &frame);
+
+        assert!(output.contains("\x1b[1;1H"));
+        assert!(output.contains("abc"));
+        assert_eq!(output.matches('H').count(), 1);
+    }
+
+    #[test]
+    fn render_moves_explicitly_after_wide_chars() {
+        let mut backend = memory_backend(8, 1);
+        let mut frame = Frame::new(8, 1);
+        frame.print(0, 0, "界ab");
+
+        let output = render_to_string(&mut backend, &frame);
+
+        assert!(output.contains("\x1b[1;1H界"));
+        assert!(output.contains("\x1b[1;2Hab"));
+
+        frame.print(0, 0, "─│›ab");
+        let narrow = render_to_string(&mut backend, &frame);
+        assert!(narrow.contains("─│›ab"));
+        assert_eq!(narrow.matches('H').count(), 1);
+    }
+
+    #[test]
+    fn render_skips_move_when_cursor_is_already_in_place() {
+        let mut backend = memory_backend(6, 1);
+        let mut frame = Frame::new(6, 1);
+        frame.print_styled(0, 0, "ab", Style::new().fg(Color::Ansi(1)));
+        frame.print_styled(2, 0, "cd", Style::new().fg(Color::Ansi(2)));
+
+        let output = render_to_string(&mut backend, &frame);
+
+        assert_eq!(output.matches("\x1b[1;").count(), 1);
+        assert!(output.contains("ab"));
+        assert!(output.contains("cd"));
+    }
+
+    #[test]
+    fn render_moves_cursor_over_unchanged_cells() {
+        let mut backend = memory_backend(6, 2);
+        let mut frame = Frame::new(6, 2);
+        frame.print(0, 0, "a");
+        frame.print(4, 0, "b");
+        frame.print(1, 1, "c");
+
+        let output = render_to_string(&mut backend, &frame);
+
+        assert!(output.contains("\x1b[1;1Ha"));
+        assert!(output.contains("\x1b[1;5Hb"));
+        assert!(output.contains("\x1b[2;2Hc"));
+    }
+
+    #[test]
+    fn render_positions_and_shows_frame_cursor() {
+        let mut backend = memory_backend(6, 2);
+        let mut frame = Frame::new(6, 2);
+        frame.print(0, 0, "ab");
+        frame.set_cursor(4, 1);
+        frame.set_cursor_shape(CursorShape::Bar, false);
+
+        let output = render_to_string(&mut backend, &frame);
+
+        let tail = output.rsplit("ab").next().expect("text is printed");
+        assert!(tail.contains("\x1b[2;5H"));
+        assert!(tail.contains("\x1b[6 q"));
+        assert!(tail.contains("\x1b[?25h"));
+    }
+
+    #[test]
+    fn render_repositions_cursor_after_resize() {
+        let mut backend = memory_backend(6, 1);
+        let mut frame = Frame::new(6, 1);
+        frame.set_cursor(2, 0);
+        render_to_string(&mut backend, &frame);
+
+        let mut resized = Frame::new(8, 2);
+        resized.set_cursor(2, 0);
+        let output = render_to_string(&mut backend, &resized);
+        assert!(output.contains("\x1b[1;3H"));
+    }
+
+    #[test]
+    fn render_only_emits_cursor_changes() {
+        let mut backend = memory_backend(6, 1);
+        let mut frame = Frame::new(6, 1);
+        frame.set_cursor(2, 0);
+        render_to_string(&mut backend, &frame);
+
+        let unchanged = render_to_string(&mut backend, &frame);
+        assert!(!unchanged.contains('H'));
+        assert!(!unchanged.contains(" q"));
+        assert!(!unchanged.contains("?25"));
+
+        frame.hide_cursor();
+        let hidden = render_to_string(&mut backend, &frame);
+        assert!(hidden.contains("\x1b[?25l"));
+    }
+
+    #[test]
+    fn render_emits_nothing_but_reset_for_identical_frames() {
+        let mut backend = memory_backend(4, 1);
+        let mut frame = Frame::new(4, 1);
+        frame.print(0, 0, "abcd");
+        render_to_string(&mut backend, &frame);
+
+        let output = render_to_string(&mut backend, &frame);
+
+        assert!(!output.contains('H'));
+        assert!(!output.contains("abcd"));
+    }
 
     #[test]
     fn map_color_supports_ansi_and_rgb() {
-        assert_eq!(map_color(Color::Default), CrosstermColor::Reset);
-        assert_eq!(map_color(Color::Ansi(42)), CrosstermColor::AnsiValue(42));
+        let depth = ColorDepth::TrueColor;
+        assert_eq!(map_color(Color::Default, depth), CrosstermColor::Reset);
+        assert_eq!(
+            map_color(Color::Ansi(42), depth),
+            CrosstermColor::AnsiValue(42)
+        );
         assert_eq!(
-            map_color(Color::Rgb(1, 2, 3)),
+            map_color(Color::Rgb(1, 2, 3), depth),
             CrosstermColor::Rgb { r: 1, g: 2, b: 3 }
         );
     }
+
+    #[test]
+    fn map_color_uses_palette_index_for_named_colors() {
+        assert_eq!(
+            map_color(Color::Red, ColorDepth::TrueColor),
+            CrosstermColor::AnsiValue(1)
+        );
+        assert_eq!(
+            map_color(Color::BrightWhite, ColorDepth::Ansi16),
+            CrosstermColor::AnsiValue(15)
+        );
+        assert_eq!(
+            map_color(Color::Blue, ColorDepth::Monochrome),
+            CrosstermColor::Reset
+        );
+    }
+
+    #[test]
+    fn map_color_downsamples_rgb_to_ansi256() {
+        let depth = ColorDepth::Ansi256;
+        assert_eq!(
+            map_color(Color::Rgb(0, 175, 255), depth),
+            CrosstermColor::AnsiValue(39)
+        );
+        assert_eq!(
+            map_color(Color::Rgb(28, 28, 28), depth),
+            CrosstermColor::AnsiValue(234)
+        );
+        assert_eq!(
+            map_color(Color::Ansi(200), depth),
+            CrosstermColor::AnsiValue(200)
+        );
+    }
+
+    #[test]
+    fn map_color_downsamples_to_ansi16() {
+        let depth = ColorDepth::Ansi16;
+        assert_eq!(
+            map_color(Color::Rgb(250, 10, 10), depth),
+            CrosstermColor::AnsiValue(9)
+        );
+        assert_eq!(
+            map_color(Color::Ansi(16), depth),
+            CrosstermColor::AnsiValue(0)
+        );
+        assert_eq!(
+            map_color(Color::Ansi(3), depth),
+            CrosstermColor::AnsiValue(3)
+        );
+    }
+
+    #[test]
+    fn map_color_monochrome_resets_everything() {
+        let depth = ColorDepth::Monochrome;
+        assert_eq!(map_color(Color::Ansi(39), depth), CrosstermColor::Reset);
+        assert_eq!(
+            map_color(Color::Rgb(255, 0, 0), depth),
+            CrosstermColor::Reset
+        );
+    }
+
+    #[test]
+    fn monochrome_reverse_keeps_selection_visible() {
+        let selected = Style::new().fg(Color::Ansi(16)).bg(Color::Ansi(39));
+        let panel = Style::new().bg(Color::Rgb(22, 32, 56));
+
+        assert!(needs_monochrome_reverse(selected));
+        assert!(!needs_monochrome_reverse(panel));
+        assert!(!needs_monochrome_reverse(Style::new().fg(Color::Ansi(252))));
+    }
+
+    #[test]
+    fn detects_color_depth_from_environment_values() {
+        let detect = ColorDepth::from_env_values;
+        assert_eq!(
+            detect(Some("1"), Some("truecolor"), None),
+            ColorDepth::Monochrome
+        );
+        assert_eq!(
+            detect(Some(""), Some("truecolor"), None),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect(None, Some("24bit"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect(None, None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(detect(None, None, Some("xterm")), ColorDepth::Ansi16);
+        assert_eq!(detect(None, None, Some("dumb")), ColorDepth::Monochrome);
+        assert_eq!(detect(None, None, None), ColorDepth::Ansi16);
+    }
+
+    #[test]
+    fn every_modifier_maps_to_a_distinct_attribute() {
+        let modifiers = MODIFIER_ATTRIBUTES.map(|(modifier, _)| modifier);
+        let attributes = MODIFIER_ATTRIBUTES.map(|(_, attribute)| attribute);
+
+        for modifier in Modifier::ALL {
+            assert!(
+                modifiers.contains(&modifier),
+                "{modifier:?} has no attribute"
+            );
+        }
+        for (idx, attribute) in attributes.iter().enumerate() {
+            assert!(!attributes[idx + 1..].contains(attribute));
+        }
+        assert!(attributes.contains(&Attribute::Undercurled));
+        assert!(attributes.contains(&Attribute::DoubleUnderlined));
+    }
 }
@@ -0,0 +1,510 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Write},
+    path::Path,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Color, Frame, Modifier, Style};
+
+pub struct CastRecorder<W: Write, C: Write> {
+    inner: W,
+    cast: C,
+    started: Instant,
+    pending: Vec<u8>,
+}
+
+impl<W: Write, C: Write> CastRecorder<W, C> {
+    pub fn new(inner: W, mut cast: C, width: u16, height: u16) -> io::Result<Self> {
+        let header = CastHeader {
+            version: 2,
+            width,
+            height,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .ok(),
+            env: Some(CastEnv {
+                term: env::var("TERM").ok(),
+            }),
+        };
+        let line = serde_json::to_string(&header).map_err(io::Error::other)?;
+        writeln!(cast, "{line}")?;
+
+        Ok(Self {
+            inner,
+            cast,
+            started: Instant::now(),
+            pending: Vec::new(),
+        })
+    }
+
+    pub fn resize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        self.flush_pending()?;
+        self.write_event("r", &format!("{width}x{height}"))?;
+        self.cast.flush()
+    }
+
+    pub fn into_inner(self) -> (W, C) {
+        (self.inner, self.cast)
+    }
+
+    fn flush_pending(&mut self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let data = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending.clear();
+        self.write_event("o", &data)
+    }
+
+    fn write_event(&mut self, code: &str, data: &str) -> io::Result<()> {
+        let elapsed = self.started.elapsed().as_micros() as f64 / 1_000_000.0;
+        let line = serde_json::to_string(&(elapsed, code, data)).map_err(io::Error::other)?;
+        writeln!(self.cast, "{line}")
+    }
+}
+
+impl<W: Write, C: Write> Write for CastRecorder<W, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write_all(buf)?;
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+        self.flush_pending()?;
+        self.cast.flush()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cast {
+    pub width: u16,
+    pub height: u16,
+    pub events: Vec<CastEvent>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CastEvent {
+    pub time: f64,
+    pub kind: CastEventKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CastEventKind {
+    Output(String),
+    Resize { width: u16, height: u16 },
+}
+
+impl Cast {
+    pub fn from_json_str(input: &str) -> Result<Self, CastError> {
+        let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+        let header_line = lines
+            .next()
+            .ok_or_else(|| CastError::Invalid("cast is empty".to_string()))?;
+        let header: CastHeader = serde_json::from_str(header_line)
+            .map_err(|err| CastError::Parse(format!("invalid cast header: {err}")))?;
+        if header.version != 2 {
+            return Err(CastError::Invalid(format!(
+                "unsupported cast version {}",
+                header.version
+            )));
+        }
+
+        let mut events = Vec::new();
+        for (idx, line) in lines.enumerate() {
+            let (time, code, data): (f64, String, String) =
+                serde_json::from_str(line).map_err(|err| {
+                    CastError::Parse(format!("invalid cast event {}: {err}", idx + 1))
+                })?;
+            let kind = match code.as_str() {
+                "o" => CastEventKind::Output(data),
+                "r" => parse_resize(&data)
+                    .ok_or_else(|| CastError::Invalid(format!("invalid resize event `{data}`")))?,
+                _ => continue,
+            };
+            events.push(CastEvent { time, kind });
+        }
+
+        Ok(Self {
+            width: header.width,
+            height: header.height,
+            events,
+        })
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CastError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path)
+            .map_err(|err| CastError::Io(format!("failed to read {}: {err}", path.display())))?;
+        Self::from_json_str(&data)
+    }
+
+    pub fn replay(&self) -> Frame {
+        self.replay_until(f64::INFINITY)
+    }
+
+    pub fn replay_until(&self, time: f64) -> Frame {
+        let mut screen = ReplayScreen::new(self.width, self.height);
+        for event in self.events.iter().take_while(|event| event.time <= time) {
+            match &event.kind {
+                CastEventKind::Output(data) => screen.feed(data),
+                CastEventKind::Resize { width, height } => screen.resize(*width, *height),
+            }
+        }
+        screen.frame
+    }
+}
+
+#[derive(Debug)]
+pub enum CastError {
+    Io(String),
+    Parse(String),
+    Invalid(String),
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Io(message) => write!(f, "{message}"),
+            CastError::Parse(message) => write!(f, "{message}"),
+            CastError::Invalid(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CastHeader {
+    version: u8,
+    width: u16,
+    height: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    env: Option<CastEnv>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CastEnv {
+    #[serde(rename = "TERM", default, skip_serializing_if = "Option::is_none")]
+    term: Option<String>,
+}
+
+fn parse_resize(data: &str) -> Option<CastEventKind> {
+    let (width, height) = data.split_once('x')?;
+    Some(CastEventKind::Resize {
+        width: width.trim().parse().ok()?,
+        height: height.trim().parse().ok()?,
+    })
+}
+
+struct ReplayScreen {
+    frame: Frame,
+    x: u16,
+    y: u16,
+    style: Style,
+}
+
+impl ReplayScreen {
+    fn new(width: u16, height: u16) -> Self {
+        Self {
+            frame: Frame::new(width, height),
+            x: 0,
+            y: 0,
+            style: Style::default(),
+        }
+    }
+
+    fn resize(&mut self, width: u16, height: u16) {
+        let mut resized = Frame::new(width, height);
+        for y in 0..height.min(self.frame.height()) {
+            for x in 0..width.min(self.frame.width()) {
+                if let (Some(ch), Some(style)) =
+                    (self.frame.char_at(x, y), self.frame.style_at(x, y))
+                {
+                    resized.print_styled(x, y, &ch.to_string(), style);
+                }
+            }
+        }
+        self.frame = resized;
+        self.x = self.x.min(width.saturating_sub(1));
+        self.y = self.y.min(height.saturating_sub(1));
+    }
+
+    fn feed(&mut self, data: &str) {
+        let mut chars = data.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\x1b' => {
+                    if chars.peek() != Some(&'[') {
+                        chars.next();
+                        continue;
+                    }
+                    chars.next();
+
+                    let mut params = String::new();
+                    let mut final_byte = None;
+                    for next in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&next) {
+                            final_byte = Some(next);
+                            break;
+                        }
+                        params.push(next);
+                    }
+                    if let Some(final_byte) = final_byte {
+                        self.apply_csi(&params, final_byte);
+                    }
+                }
+                '\r' => self.x = 0,
+                '\n' => self.y = self.y.saturating_add(1),
+                ch if ch.is_control() => {}
+                ch => {
+                    self.frame
+                        .print_styled(self.x, self.y, &ch.to_string(), self.style);
+                    self.x = self.x.saturating_add(1);
+                }
+            }
+        }
+    }
+
+    fn apply_csi(&mut self, params: &str, final_byte: char) {
+        if params.starts_with('?') {
+            return;
+        }
+
+        match final_byte {
+            'H' | 'f' => {
+                let mut parts = params.split(';');
+                let row = parse_position(parts.next());
+                let column = parse_position(parts.next());
+                self.y = row;
+                self.x = column;
+            }
+            'J' if params == "2" => self.frame.clear(),
+            'm' => self.apply_sgr(params),
+            _ => {}
+        }
+    }
+
+    fn apply_sgr(&mut self, params: &str) {
+        let codes: Vec<&str> = if params.is_empty() {
+            vec!["0"]
+        } else {
+            params.split(';').collect()
+        };
+
+        let mut idx = 0;
+        while idx < codes.len() {
+            let code = codes[idx];
+            idx += 1;
+            match code {
+                "0" => self.style = Style::default(),
+                "38" | "48" | "58" => {
+                    let Some((color, used)) = parse_extended_color(&codes[idx..]) else {
+                        break;
+                    };
+                    idx += used;
+                    self.style = match code {
+                        "38" => self.style.fg(color),
+                        "48" => self.style.bg(color),
+                        _ => self.style.underline_color(color),
+                    };
+                }
+                "39" => self.style.fg = None,
+                "49" => self.style.bg = None,
+                "59" => self.style.underline_color = None,
+                code => {
+                    if let Some(modifier) = sgr_modifier(code) {
+                        self.style = self.style.modifier(modifier);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn parse_position(value: Option<&str>) -> u16 {
+    value
+        .and_then(|value| value.parse::<u16>().ok())
+        .unwrap_or(1)
+        .saturating_sub(1)
+}
+
+fn parse_extended_color(codes: &[&str]) -> Option<(Color, usize)> {
+    match *codes.first()? {
+        "5" => Some((Color::Ansi(codes.get(1)?.parse().ok()?), 2)),
+        "2" => Some((
+            Color::Rgb(
+                codes.get(1)?.parse().ok()?,
+                codes.get(2)?.parse().ok()?,
+                codes.get(3)?.parse().ok()?,
+            ),
+            4,
+        )),
+        _ => None,
+    }
+}
+
+fn sgr_modifier(code: &str) -> Option<Modifier> {
+    match code {
+        "1" => Some(Modifier::Bold),
+        "2" => Some(Modifier::Dim),
+        "3" => Some(Modifier::Italic),
+        "4" | "4:1" => Some(Modifier::Underline),
+        "4:2" | "21" => Some(Modifier::DoubleUnderline),
+        "4:3" => Some(Modifier::CurlyUnderline),
+        "5" | "6" => Some(Modifier::Blink),
+        "7" => Some(Modifier::Reverse),
+        "8" => Some(Modifier::Hidden),
+        "9" => Some(Modifier::Strikethrough),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{backend::TerminalBackend, Color, ColorDepth, Frame, Modifier, Style};
+
+    use super::{Cast, CastEventKind, CastRecorder};
+
+    fn record(frames: &[Frame]) -> String {
+        let first = &frames[0];
+        let recorder = CastRecorder::new(Vec::new(), Vec::new(), first.width(), first.height())
+            .expect("recording to memory succeeds");
+        let mut backend = TerminalBackend::with_writer(recorder, first.width(), first.height())
+            .with_color_depth(ColorDepth::TrueColor)
+            .with_synchronized_output(true);
+
+        for (idx, frame) in frames.iter().enumerate() {
+            if idx > 0
+                && (frame.width(), frame.height())
+                    != (frames[idx - 1].width(), frames[idx - 1].height())
+            {
+                backend
+                    .writer_mut()
+                    .resize(frame.width(), frame.height())
+                    .expect("recording to memory succeeds");
+            }
+            backend.render(frame).expect("render to memory succeeds");
+        }
+
+        let (_, cast) = backend.into_writer().into_inner();
+        String::from_utf8(cast).expect("cast is utf-8")
+    }
+
+    #[test]
+    fn recorder_writes_v2_header_and_output_events() {
+        let mut frame = Frame::new(6, 2);
+        frame.print(0, 0, "hi");
+
+        let cast = record(&[frame]);
+        let mut lines = cast.lines();
+        let header: serde_json::Value =
+            serde_json::from_str(lines.next().expect("header line")).expect("header is json");
+        let event: (f64, String, String) =
+            serde_json::from_str(lines.next().expect("event line")).expect("event is json");
+
+        assert_eq!(header["version"], 2);
+        assert_eq!(header["width"], 6);
+        assert_eq!(header["height"], 2);
+        assert_eq!(event.1, "o");
+        assert!(event.2.contains("hi"));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn recorder_passes_bytes_through_to_inner_writer() {
+        let mut recorder =
+            CastRecorder::new(Vec::new(), Vec::new(), 4, 1).expect("recording to memory succeeds");
+        std::io::Write::write_all(&mut recorder, b"abc").expect("write succeeds");
+        std::io::Write::flush(&mut recorder).expect("flush succeeds");
+
+        let (inner, _) = recorder.into_inner();
+        assert_eq!(inner, b"abc");
+    }
+
+    #[test]
+    fn replay_reconstructs_rendered_frame() {
+        let mut first = Frame::new(8, 3);
+        first.print_styled(
+            1,
+            0,
+            "pulse",
+            Style::new()
+                .fg(Color::Rgb(30, 144, 255))
+                .bg(Color::Ansi(17))
+                .modifier(Modifier::Bold),
+        );
+        let mut second = first.clone();
+        second.print_styled(
+            0,
+            2,
+            "typo",
+            Style::new()
+                .underline_color(Color::Ansi(196))
+                .modifier(Modifier::CurlyUnderline),
+        );
+
+        let cast = Cast::from_json_str(&record(&[first, second.clone()])).expect("cast parses");
+        let replayed = cast.replay();
+
+        for y in 0..second.height() {
+            for x in 0..second.width() {
+                assert_eq!(replayed.char_at(x, y), second.char_at(x, y));
+                assert_eq!(replayed.style_at(x, y), second.style_at(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn replay_applies_resize_events() {
+        let mut small = Frame::new(4, 1);
+        small.print(0, 0, "ab");
+        let mut large = Frame::new(6, 2);
+        large.print(0, 1, "cd");
+
+        let cast = Cast::from_json_str(&record(&[small, large])).expect("cast parses");
+
+        assert!(cast.events.iter().any(|event| matches!(
+            event.kind,
+            CastEventKind::Resize {
+                width: 6,
+                height: 2
+            }
+        )));
+        let replayed = cast.replay();
+        assert_eq!((replayed.width(), replayed.height()), (6, 2));
+        assert_eq!(replayed.char_at(0, 0), Some(' '));
+        assert_eq!(replayed.char_at(0, 1), Some('c'));
+    }
+
+    #[test]
+    fn replay_until_stops_at_timestamp() {
+        let input = r#"{"version": 2, "width": 4, "height": 1}
+[0.5, "o", "ab"]
+[1.5, "o", "\u001b[1;1Hzz"]
+"#;
+        let cast = Cast::from_json_str(input).expect("cast parses");
+
+        assert_eq!(cast.replay_until(1.0).char_at(0, 0), Some('a'));
+        assert_eq!(cast.replay().char_at(0, 0), Some('z'));
+    }
+
+    #[test]
+    fn invalid_casts_are_rejected() {
+        assert!(Cast::from_json_str("").is_err());
+        assert!(Cast::from_json_str(r#"{"version": 1, "width": 4, "height": 1}"#).is_err());
+        assert!(Cast::from_json_str(
+            r#"{"version": 2, "width": 4, "height": 1}
+[0.1, "r", "wide"]"#
+        )
+        .is_err());
+    }
+}
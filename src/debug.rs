@@ -0,0 +1,631 @@
+use std::{collections::VecDeque, fmt::Debug};
+
+use crossterm::event::{KeyCode, KeyEventKind};
+
+use crate::{
+    App, Block, BorderType, Color, Command, Event, Frame, List, Padding, Rect, ResolvedLayout,
+    Style, WriteMode,
+};
+
+const DEFAULT_CAPACITY: usize = 256;
+const OVERLAY_MAX_WIDTH: u16 = 48;
+const LAYOUT_COLORS: [Color; 6] = [
+    Color::Ansi(196),
+    Color::Ansi(46),
+    Color::Ansi(33),
+    Color::Ansi(226),
+    Color::Ansi(201),
+    Color::Ansi(51),
+];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DebugMsg<Msg> {
+    App(Msg),
+    ToggleOverlay,
+    StepBack,
+    StepForward,
+    Resume,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    pub message: String,
+    pub command: String,
+}
+
+pub struct Debugger<A: App> {
+    app: A,
+    history: VecDeque<HistoryEntry>,
+    snapshots: VecDeque<Option<A>>,
+    snapshot: Option<fn(&A) -> A>,
+    capacity: usize,
+    overlay_visible: bool,
+    cursor: Option<usize>,
+}
+
+impl<A: App> Debugger<A> {
+    pub fn new(app: A) -> Self {
+        Self {
+            app,
+            history: VecDeque::new(),
+            snapshots: VecDeque::new(),
+            snapshot: None,
+            capacity: DEFAULT_CAPACITY,
+            overlay_visible: false,
+            cursor: None,
+        }
+    }
+
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    pub fn overlay_visible(mut self, visible: bool) -> Self {
+        self.overlay_visible = visible;
+        self
+    }
+
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut A {
+        &mut self.app
+    }
+
+    pub fn into_inner(self) -> A {
+        self.app
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.history.iter()
+    }
+
+    pub fn is_overlay_visible(&self) -> bool {
+        self.overlay_visible
+    }
+
+    pub fn viewing(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    pub fn viewed_state(&self) -> &A {
+        self.cursor
+            .and_then(|idx| self.snapshots.get(idx))
+            .and_then(Option::as_ref)
+            .unwrap_or(&self.app)
+    }
+
+    fn push_entry(&mut self, message: String, command: String) {
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+            self.snapshots.pop_front();
+            self.cursor = self.cursor.and_then(|idx| idx.checked_sub(1));
+        }
+
+        let snapshot = self.snapshot.map(|snapshot| snapshot(&self.app));
+        self.history.push_back(HistoryEntry { message, command });
+        self.snapshots.push_back(snapshot);
+    }
+
+    fn step_back(&mut self) {
+        if self.snapshot.is_none() || self.history.is_empty() {
+            return;
+        }
+
+        let last = self.history.len() - 1;
+        self.cursor = Some(match self.cursor {
+            Some(idx) => idx.saturating_sub(1),
+            None => last.saturating_sub(1),
+        });
+    }
+
+    fn step_forward(&mut self) {
+        let last = self.history.len().saturating_sub(1);
+        self.cursor = match self.cursor {
+            Some(idx) if idx + 1 < last => Some(idx + 1),
+            _ => None,
+        };
+    }
+
+    fn render_overlay(&self, frame: &mut Frame) {
+        let width = frame.width().min(OVERLAY_MAX_WIDTH);
+        let area = Rect::new(
+            frame.width().saturating_sub(width),
+            0,
+            width,
+            frame.height(),
+        );
+        let block = Block::new()
+            .title(match self.cursor {
+                Some(idx) => format!("debug · viewing #{idx}"),
+                None => format!("debug · {} messages", self.history.len()),
+            })
+            .style(Style::new().fg(Color::Ansi(250)).bg(Color::Rgb(16, 16, 24)))
+            .border_style(Style::new().fg(Color::Ansi(214)).bg(Color::Rgb(16, 16, 24)))
+            .padding(Padding::symmetric(0, 1));
+        block.render(frame, area);
+
+        let items = self
+            .history
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| format!("#{idx} {} → {}", entry.message, entry.command));
+        let selected = self
+            .cursor
+            .unwrap_or_else(|| self.history.len().saturating_sub(1));
+        List::new(items)
+            .selected(selected)
+            .item_style(Style::new().fg(Color::Ansi(250)).bg(Color::Rgb(16, 16, 24)))
+            .selected_style(Style::new().fg(Color::Ansi(16)).bg(Color::Ansi(214)))
+            .render(frame, block.inner_area(area));
+    }
+}
+
+impl<A: App + Clone> Debugger<A> {
+    pub fn with_time_travel(app: A) -> Self {
+        let mut debugger = Self::new(app);
+        debugger.snapshot = Some(A::clone);
+        debugger
+    }
+}
+
+impl<A> App for Debugger<A>
+where
+    A: App,
+    A::Msg: Debug,
+{
+    type Msg = DebugMsg<A::Msg>;
+
+    fn init(&mut self) {
+        self.app.init();
+        self.push_entry("init".to_string(), "none".to_string());
+    }
+
+    fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg> {
+        match msg {
+            DebugMsg::App(msg) => {
+                let message = format!("{msg:?}");
+                let command = self.app.update(msg);
+                self.push_entry(message, describe_command(&command));
+                return command.map(DebugMsg::App);
+            }
+            DebugMsg::ToggleOverlay => self.overlay_visible = !self.overlay_visible,
+            DebugMsg::StepBack => self.step_back(),
+            DebugMsg::StepForward => self.step_forward(),
+            DebugMsg::Resume => self.cursor = None,
+        }
+
+        Command::none()
+    }
+
+    fn view(&self, frame: &mut Frame) {
+        self.viewed_state().view(frame);
+
+        if self.overlay_visible {
+            self.render_overlay(frame);
+        }
+    }
+}
+
+pub fn debug_keys<Msg>(
+    mut map_event: impl FnMut(Event) -> Option<Msg>,
+) -> impl FnMut(Event) -> Option<DebugMsg<Msg>> {
+    move |event| {
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press {
+                match key.code {
+                    KeyCode::F(12) => return Some(DebugMsg::ToggleOverlay),
+                    KeyCode::F(9) => return Some(DebugMsg::StepBack),
+                    KeyCode::F(10) => return Some(DebugMsg::StepForward),
+                    KeyCode::F(11) => return Some(DebugMsg::Resume),
+                    _ => {}
+                }
+            }
+        }
+
+        map_event(event).map(DebugMsg::App)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LayoutDebugMsg<Msg> {
+    App(Msg),
+    Toggle,
+}
+
+pub struct LayoutDebugger<A: App> {
+    app: A,
+    layout: fn(&A, Rect) -> ResolvedLayout,
+    visible: bool,
+}
+
+impl<A: App> LayoutDebugger<A> {
+    pub fn new(app: A, layout: fn(&A, Rect) -> ResolvedLayout) -> Self {
+        Self {
+            app,
+            layout,
+            visible: false,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut A {
+        &mut self.app
+    }
+
+    pub fn into_inner(self) -> A {
+        self.app
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+impl<A: App> App for LayoutDebugger<A> {
+    type Msg = LayoutDebugMsg<A::Msg>;
+
+    fn init(&mut self) {
+        self.app.init();
+    }
+
+    fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg> {
+        match msg {
+            LayoutDebugMsg::App(msg) => self.app.update(msg).map(LayoutDebugMsg::App),
+            LayoutDebugMsg::Toggle => {
+                self.visible = !self.visible;
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&self, frame: &mut Frame) {
+        self.app.view(frame);
+
+        if self.visible {
+            let area = Rect::new(0, 0, frame.width(), frame.height());
+            render_layout_debug(frame, &(self.layout)(&self.app, area));
+        }
+    }
+}
+
+pub fn layout_debug_keys<Msg>(
+    mut map_event: impl FnMut(Event) -> Option<Msg>,
+) -> impl FnMut(Event) -> Option<LayoutDebugMsg<Msg>> {
+    move |event| {
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press && key.code == KeyCode::F(8) {
+                return Some(LayoutDebugMsg::Toggle);
+            }
+        }
+
+        map_event(event).map(LayoutDebugMsg::App)
+    }
+}
+
+pub fn render_layout_debug(frame: &mut Frame, layout: &ResolvedLayout) {
+    let zones: Vec<_> = layout
+        .zones()
+        .iter()
+        .enumerate()
+        .filter(|(_, zone)| zone.area.width > 0 && zone.area.height > 0)
+        .map(|(idx, zone)| (zone, LAYOUT_COLORS[idx % LAYOUT_COLORS.len()]))
+        .collect();
+
+    frame.with_write_mode(WriteMode::Patch, |frame| {
+        for (zone, color) in &zones {
+            frame.draw_box(zone.area, BorderType::Unicode, Style::new().fg(*color));
+        }
+
+        let mut labels = Vec::new();
+        for (zone, color) in zones.iter().rev() {
+            let area = zone.area;
+            if area.width < 3 {
+                continue;
+            }
+
+            let x = area.x.saturating_add(1);
+            let y = if labels.contains(&(x, area.y)) && area.height > 1 {
+                area.y.saturating_add(area.height - 1)
+            } else {
+                area.y
+            };
+            labels.push((x, y));
+
+            let label: String = format!(
+                "{} ({},{}) {}x{}",
+                zone.name, area.x, area.y, area.width, area.height
+            )
+            .chars()
+            .take((area.width - 2) as usize)
+            .collect();
+            frame.print_styled(x, y, &label, Style::new().fg(Color::Ansi(16)).bg(*color));
+        }
+    });
+}
+
+fn describe_command<Msg: Debug>(command: &Command<Msg>) -> String {
+    match command {
+        Command::None => "none".to_string(),
+        Command::Quit => "quit".to_string(),
+        Command::Emit(msg) => format!("emit({msg:?})"),
+        Command::Batch(commands) => format!(
+            "batch[{}]",
+            commands
+                .iter()
+                .map(describe_command)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::{
+        App, Color, Command, Constraint, Direction, Event, Frame, LayoutNode, Rect, ResolvedLayout,
+        Slot,
+    };
+
+    use super::{
+        debug_keys, layout_debug_keys, render_layout_debug, DebugMsg, Debugger, LayoutDebugMsg,
+        LayoutDebugger,
+    };
+
+    #[derive(Clone, Default)]
+    struct Counter {
+        value: i32,
+    }
+
+    #[derive(Debug)]
+    enum Msg {
+        Add(i32),
+        AddTwice(i32),
+        Quit,
+    }
+
+    impl App for Counter {
+        type Msg = Msg;
+
+        fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg> {
+            match msg {
+                Msg::Add(amount) => {
+                    self.value += amount;
+                    Command::none()
+                }
+                Msg::AddTwice(amount) => Command::batch([
+                    Command::emit(Msg::Add(amount)),
+                    Command::emit(Msg::Add(amount)),
+                ]),
+                Msg::Quit => Command::quit(),
+            }
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            frame.print(0, 0, &format!("value={}", self.value));
+        }
+    }
+
+    #[test]
+    fn records_messages_and_commands() {
+        let mut debugger = Debugger::new(Counter::default());
+        debugger.init();
+
+        let command = debugger.update(DebugMsg::App(Msg::AddTwice(2)));
+        debugger.update(DebugMsg::App(Msg::Quit));
+
+        assert!(matches!(command, Command::Batch(_)));
+        let history: Vec<_> = debugger
+            .history()
+            .map(|entry| (entry.message.as_str(), entry.command.as_str()))
+            .collect();
+        assert_eq!(
+            history,
+            vec![
+                ("init", "none"),
+                ("AddTwice(2)", "batch[emit(Add(2)), emit(Add(2))]"),
+                ("Quit", "quit"),
+            ]
+        );
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest_entries() {
+        let mut debugger = Debugger::new(Counter::default()).capacity(2);
+        debugger.init();
+        debugger.update(DebugMsg::App(Msg::Add(1)));
+        debugger.update(DebugMsg::App(Msg::Add(2)));
+
+        let messages: Vec<_> = debugger
+            .history()
+            .map(|entry| entry.message.clone())
+            .collect();
+        assert_eq!(messages, vec!["Add(1)", "Add(2)"]);
+    }
+
+    #[test]
+    fn time_travel_renders_earlier_states() {
+        let mut debugger = Debugger::with_time_travel(Counter::default());
+        debugger.init();
+        debugger.update(DebugMsg::App(Msg::Add(1)));
+        debugger.update(DebugMsg::App(Msg::Add(10)));
+
+        debugger.update(DebugMsg::StepBack);
+        let mut frame = Frame::new(20, 2);
+        debugger.view(&mut frame);
+        assert_eq!(debugger.viewing(), Some(1));
+        assert_eq!(frame.row_text(0).trim_end(), "value=1");
+
+        debugger.update(DebugMsg::StepBack);
+        frame.clear();
+        debugger.view(&mut frame);
+        assert_eq!(frame.row_text(0).trim_end(), "value=0");
+
+        debugger.update(DebugMsg::StepForward);
+        debugger.update(DebugMsg::StepForward);
+        frame.clear();
+        debugger.view(&mut frame);
+        assert_eq!(debugger.viewing(), None);
+        assert_eq!(frame.row_text(0).trim_end(), "value=11");
+        assert_eq!(debugger.app().value, 11);
+    }
+
+    #[test]
+    fn evicting_the_viewed_entry_resumes_live_view() {
+        let mut debugger = Debugger::with_time_travel(Counter::default()).capacity(3);
+        debugger.init();
+        debugger.update(DebugMsg::App(Msg::Add(1)));
+        debugger.update(DebugMsg::App(Msg::Add(2)));
+        debugger.update(DebugMsg::StepBack);
+        debugger.update(DebugMsg::StepBack);
+        assert_eq!(debugger.viewing(), Some(0));
+
+        debugger.update(DebugMsg::App(Msg::Add(3)));
+        assert_eq!(debugger.viewing(), None);
+        assert_eq!(debugger.viewed_state().value, 6);
+
+        debugger.update(DebugMsg::StepBack);
+        debugger.update(DebugMsg::App(Msg::Add(4)));
+        assert_eq!(debugger.viewing(), Some(0));
+        assert_eq!(debugger.viewed_state().value, 3);
+    }
+
+    #[test]
+    fn step_back_without_snapshots_keeps_live_view() {
+        let mut debugger = Debugger::new(Counter::default());
+        debugger.init();
+        debugger.update(DebugMsg::App(Msg::Add(3)));
+
+        debugger.update(DebugMsg::StepBack);
+
+        assert_eq!(debugger.viewing(), None);
+    }
+
+    #[test]
+    fn overlay_lists_history_when_visible() {
+        let mut debugger = Debugger::new(Counter::default());
+        debugger.init();
+        debugger.update(DebugMsg::App(Msg::Add(4)));
+        debugger.update(DebugMsg::ToggleOverlay);
+
+        let mut frame = Frame::new(60, 6);
+        debugger.view(&mut frame);
+
+        assert!(debugger.is_overlay_visible());
+        assert!(frame
+            .row_text(0)
+            .trim_end()
+            .starts_with("value=4     ┌ debug · 2 messages ─"));
+        assert!(frame.row_text(2).trim_end().contains("#1 Add(4) → none"));
+    }
+
+    #[test]
+    fn debug_keys_intercepts_function_keys() {
+        let mut mapper = debug_keys(|event| match event {
+            Event::Key(key) if key.code == KeyCode::Char('+') => Some(Msg::Add(1)),
+            _ => None,
+        });
+        let press = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        assert_eq!(
+            mapper(press(KeyCode::F(12))).map(|msg| matches!(msg, DebugMsg::ToggleOverlay)),
+            Some(true)
+        );
+        assert!(matches!(
+            mapper(press(KeyCode::F(11))),
+            Some(DebugMsg::Resume)
+        ));
+        assert!(matches!(
+            mapper(press(KeyCode::Char('+'))),
+            Some(DebugMsg::App(Msg::Add(1)))
+        ));
+        assert!(mapper(Event::Tick).is_none());
+    }
+
+    fn counter_layout(_app: &Counter, area: Rect) -> ResolvedLayout {
+        LayoutNode::split(
+            "root",
+            Direction::Horizontal,
+            [
+                Slot::new(Constraint::Fixed(12), LayoutNode::leaf("side")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("main")),
+            ],
+        )
+        .resolve(area)
+    }
+
+    #[test]
+    fn render_layout_debug_outlines_and_labels_zones() {
+        let mut frame = Frame::new(30, 4);
+        let layout = counter_layout(&Counter::default(), Rect::new(0, 0, 30, 4));
+
+        render_layout_debug(&mut frame, &layout);
+
+        assert_eq!(
+            frame.row_text(0).trim_end(),
+            "┌side (0,0)┐┌main (12,0) 18x4┐"
+        );
+        assert_eq!(
+            frame.row_text(2).trim_end(),
+            "│          ││                │"
+        );
+        assert_eq!(
+            frame.row_text(3).trim_end(),
+            "└root (0,0) 30x4─────────────┘"
+        );
+        let side = frame.style_at(1, 0).expect("label cell");
+        let main = frame.style_at(13, 0).expect("label cell");
+        assert_ne!(side.bg, main.bg);
+        assert_eq!(main.bg, Some(Color::Ansi(33)));
+    }
+
+    #[test]
+    fn render_layout_debug_handles_zones_at_max_coordinates() {
+        let mut frame = Frame::new(4, 4);
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Vertical,
+            [Slot::new(Constraint::Fill(1), LayoutNode::leaf("inner"))],
+        )
+        .resolve(Rect::new(u16::MAX, u16::MAX, 6, 6));
+
+        render_layout_debug(&mut frame, &layout);
+
+        assert_eq!(frame.row_text(0), "    ");
+    }
+
+    #[test]
+    fn layout_debugger_toggles_overlay_over_app_view() {
+        let mut debugger = LayoutDebugger::new(Counter::default(), counter_layout);
+        let mut mapper = layout_debug_keys(|_| Some(Msg::Add(2)));
+        let press = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        let msg = mapper(press(KeyCode::Char('+'))).expect("app message");
+        debugger.update(msg);
+        let mut frame = Frame::new(30, 4);
+        debugger.view(&mut frame);
+        assert_eq!(frame.row_text(0).trim_end(), "value=2");
+
+        let toggle = mapper(press(KeyCode::F(8))).expect("toggle");
+        assert!(matches!(toggle, LayoutDebugMsg::Toggle));
+        debugger.update(toggle);
+        frame.clear();
+        debugger.view(&mut frame);
+
+        assert!(debugger.is_visible());
+        assert_eq!(debugger.app().value, 2);
+        assert!(frame.row_text(0).trim_end().starts_with("┌side"));
+    }
+}
@@ -0,0 +1,395 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    queue,
+    style::{Attribute, Print, ResetColor, SetAttribute},
+};
+
+use crate::{
+    backend::{apply_style, color_rgb, ColorDepth},
+    frame::Cell,
+    Color, Frame, Modifier, Style,
+};
+
+type Rgb = (u8, u8, u8);
+
+const DEFAULT_FG: Rgb = (229, 229, 229);
+const DEFAULT_BG: Rgb = (0, 0, 0);
+const SVG_CELL_WIDTH: u32 = 9;
+const SVG_CELL_HEIGHT: u32 = 18;
+const SVG_FONT_SIZE: u32 = 15;
+const SVG_BASELINE: u32 = 14;
+
+impl Frame {
+    pub fn write_ansi(&self, out: &mut impl Write) -> io::Result<()> {
+        for row in self.rows() {
+            let mut active_style = Style::default();
+            for (style, text) in style_runs(row) {
+                apply_style(out, &mut active_style, ColorDepth::TrueColor, style)?;
+                queue!(out, Print(text))?;
+            }
+            if active_style != Style::default() {
+                queue!(out, ResetColor, SetAttribute(Attribute::Reset))?;
+            }
+            queue!(out, Print("\n"))?;
+        }
+        out.flush()
+    }
+
+    pub fn to_ansi_string(&self) -> String {
+        let mut out = Vec::new();
+        self.write_ansi(&mut out)
+            .expect("writing to memory cannot fail");
+        String::from_utf8_lossy(&out).into_owned()
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut html = format!(
+            "<pre style=\"font-family:monospace;line-height:1.2;padding:0.5em;color:{};background-color:{}\">",
+            hex(DEFAULT_FG),
+            hex(DEFAULT_BG)
+        );
+
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                html.push('\n');
+            }
+            for (style, text) in style_runs(row) {
+                let declarations = css_declarations(style);
+                if declarations.is_empty() {
+                    html.push_str(&escape_markup(&text));
+                } else {
+                    html.push_str(&format!(
+                        "<span style=\"{}\">{}</span>",
+                        declarations.join(";"),
+                        escape_markup(&text)
+                    ));
+                }
+            }
+        }
+
+        html.push_str("</pre>\n");
+        html
+    }
+
+    pub fn to_svg(&self) -> String {
+        let width = self.width() as u32 * SVG_CELL_WIDTH;
+        let height = self.height() as u32 * SVG_CELL_HEIGHT;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"{SVG_FONT_SIZE}\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            hex(DEFAULT_BG)
+        );
+
+        for (y, row) in self.rows().enumerate() {
+            let top = y as u32 * SVG_CELL_HEIGHT;
+            let mut column = 0u32;
+            for (style, text) in style_runs(row) {
+                let len = text.chars().count() as u32;
+                let x = column * SVG_CELL_WIDTH;
+                let run_width = len * SVG_CELL_WIDTH;
+                column += len;
+
+                let (fg, bg) = resolved_colors(style);
+                if let Some(bg) = bg {
+                    svg.push_str(&format!(
+                        "<rect x=\"{x}\" y=\"{top}\" width=\"{run_width}\" height=\"{SVG_CELL_HEIGHT}\" fill=\"{}\"/>\n",
+                        hex(bg)
+                    ));
+                }
+
+                if text.trim().is_empty() && !has_line_decoration(style) {
+                    continue;
+                }
+
+                svg.push_str(&format!(
+                    "<text x=\"{x}\" y=\"{}\" textLength=\"{run_width}\" lengthAdjust=\"spacingAndGlyphs\" \
+                     xml:space=\"preserve\" fill=\"{}\"{}>",
+                    top + SVG_BASELINE,
+                    hex(fg),
+                    svg_attributes(style)
+                ));
+                svg.push_str(&escape_markup(&text));
+                if style.modifiers.contains(Modifier::Blink) {
+                    svg.push_str(
+                        "<animate attributeName=\"opacity\" values=\"1;0\" dur=\"1s\" \
+                         calcMode=\"discrete\" repeatCount=\"indefinite\"/>",
+                    );
+                }
+                svg.push_str("</text>\n");
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells().chunks(self.width().max(1) as usize)
+    }
+}
+
+fn style_runs(row: &[Cell]) -> Vec<(Style, String)> {
+    let mut runs: Vec<(Style, String)> = Vec::new();
+    for cell in row {
+        match runs.last_mut() {
+            Some((style, text)) if *style == cell.style => text.push(cell.ch),
+            _ => runs.push((cell.style, cell.ch.to_string())),
+        }
+    }
+    runs
+}
+
+fn resolved_colors(style: Style) -> (Rgb, Option<Rgb>) {
+    let fg = style.fg.and_then(color_rgb);
+    let bg = style.bg.and_then(color_rgb);
+
+    if style.modifiers.contains(Modifier::Reverse) {
+        (bg.unwrap_or(DEFAULT_BG), Some(fg.unwrap_or(DEFAULT_FG)))
+    } else {
+        (fg.unwrap_or(DEFAULT_FG), bg)
+    }
+}
+
+fn has_line_decoration(style: Style) -> bool {
+    [
+        Modifier::Underline,
+        Modifier::DoubleUnderline,
+        Modifier::CurlyUnderline,
+        Modifier::Strikethrough,
+    ]
+    .iter()
+    .any(|modifier| style.modifiers.contains(*modifier))
+}
+
+fn decoration_lines(style: Style) -> Vec<&'static str> {
+    let mut lines = Vec::new();
+    if [
+        Modifier::Underline,
+        Modifier::DoubleUnderline,
+        Modifier::CurlyUnderline,
+    ]
+    .iter()
+    .any(|modifier| style.modifiers.contains(*modifier))
+    {
+        lines.push("underline");
+    }
+    if style.modifiers.contains(Modifier::Strikethrough) {
+        lines.push("line-through");
+    }
+    if style.modifiers.contains(Modifier::Blink) {
+        lines.push("blink");
+    }
+    lines
+}
+
+fn decoration_style(style: Style) -> Option<&'static str> {
+    if style.modifiers.contains(Modifier::CurlyUnderline) {
+        Some("wavy")
+    } else if style.modifiers.contains(Modifier::DoubleUnderline) {
+        Some("double")
+    } else {
+        None
+    }
+}
+
+fn underline_color(style: Style) -> Option<Rgb> {
+    style.underline_color.and_then(color_rgb)
+}
+
+fn css_declarations(style: Style) -> Vec<String> {
+    let mut declarations = Vec::new();
+    let reversed = style.modifiers.contains(Modifier::Reverse);
+    let (fg, bg) = resolved_colors(style);
+
+    if reversed || style.fg.is_some_and(|color| color != Color::Default) {
+        declarations.push(format!("color:{}", hex(fg)));
+    }
+    if let Some(bg) = bg {
+        declarations.push(format!("background-color:{}", hex(bg)));
+    }
+    if style.modifiers.contains(Modifier::Bold) {
+        declarations.push("font-weight:bold".to_string());
+    }
+    if style.modifiers.contains(Modifier::Dim) {
+        declarations.push("opacity:0.5".to_string());
+    }
+    if style.modifiers.contains(Modifier::Italic) {
+        declarations.push("font-style:italic".to_string());
+    }
+
+    let lines = decoration_lines(style);
+    if !lines.is_empty() {
+        declarations.push(format!("text-decoration-line:{}", lines.join(" ")));
+    }
+    if let Some(decoration) = decoration_style(style) {
+        declarations.push(format!("text-decoration-style:{decoration}"));
+    }
+    if let Some(color) = underline_color(style) {
+        declarations.push(format!("text-decoration-color:{}", hex(color)));
+    }
+    if style.modifiers.contains(Modifier::Hidden) {
+        declarations.push("visibility:hidden".to_string());
+    }
+
+    declarations
+}
+
+fn svg_attributes(style: Style) -> String {
+    let mut attributes = String::new();
+    if style.modifiers.contains(Modifier::Bold) {
+        attributes.push_str(" font-weight=\"bold\"");
+    }
+    if style.modifiers.contains(Modifier::Italic) {
+        attributes.push_str(" font-style=\"italic\"");
+    }
+    if style.modifiers.contains(Modifier::Dim) {
+        attributes.push_str(" opacity=\"0.5\"");
+    }
+    if style.modifiers.contains(Modifier::Hidden) {
+        attributes.push_str(" visibility=\"hidden\"");
+    }
+
+    let lines: Vec<&str> = decoration_lines(style)
+        .into_iter()
+        .filter(|line| *line != "blink")
+        .collect();
+    if !lines.is_empty() {
+        attributes.push_str(&format!(" text-decoration=\"{}\"", lines.join(" ")));
+    }
+
+    let mut css = Vec::new();
+    if let Some(decoration) = decoration_style(style) {
+        css.push(format!("text-decoration-style:{decoration}"));
+    }
+    if let Some(color) = underline_color(style) {
+        css.push(format!("text-decoration-color:{}", hex(color)));
+    }
+    if !css.is_empty() {
+        attributes.push_str(&format!(" style=\"{}\"", css.join(";")));
+    }
+
+    attributes
+}
+
+fn hex((r, g, b): Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape_markup(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, Frame, Modifier, Style};
+
+    fn sample_frame() -> Frame {
+        let mut frame = Frame::new(8, 2);
+        frame.print_styled(
+            0,
+            0,
+            "ok",
+            Style::new()
+                .fg(Color::Rgb(30, 144, 255))
+                .modifier(Modifier::Bold),
+        );
+        frame.print_styled(
+            3,
+            0,
+            "<x>",
+            Style::new()
+                .fg(Color::Red)
+                .bg(Color::Ansi(17))
+                .modifier(Modifier::Strikethrough),
+        );
+        frame.print_styled(
+            0,
+            1,
+            "typo",
+            Style::new()
+                .underline_color(Color::Ansi(196))
+                .modifier(Modifier::CurlyUnderline),
+        );
+        frame
+    }
+
+    #[test]
+    fn ansi_export_emits_one_line_per_row_with_sgr_sequences() {
+        let ansi = sample_frame().to_ansi_string();
+        let lines: Vec<&str> = ansi.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\x1b[38;2;30;144;255m"));
+        assert!(lines[0].contains("\x1b[1m"));
+        assert!(lines[0].contains("ok"));
+        assert!(lines[0].contains("\x1b[9m"));
+        assert!(lines[1].contains("typo"));
+        assert!(lines[1].contains("\x1b[4:3m"));
+    }
+
+    #[test]
+    fn ansi_export_of_plain_frame_is_plain_text() {
+        let mut frame = Frame::new(3, 2);
+        frame.print(0, 0, "abc");
+
+        assert_eq!(frame.to_ansi_string(), "abc\n   \n");
+    }
+
+    #[test]
+    fn html_export_inlines_styles_and_escapes_text() {
+        let html = sample_frame().to_html();
+
+        assert!(html.starts_with("<pre style=\""));
+        assert!(html.contains("<span style=\"color:#1e90ff;font-weight:bold\">ok</span>"));
+        assert!(html.contains("&lt;x&gt;"));
+        assert!(html.contains("background-color:#00005f"));
+        assert!(html.contains("text-decoration-line:line-through"));
+        assert!(html.contains("text-decoration-style:wavy"));
+        assert!(html.contains("text-decoration-color:#ff0000"));
+        assert!(html.trim_end().ends_with("</pre>"));
+    }
+
+    #[test]
+    fn html_export_swaps_colors_for_reverse() {
+        let mut frame = Frame::new(1, 1);
+        frame.print_styled(
+            0,
+            0,
+            "r",
+            Style::new()
+                .fg(Color::Rgb(1, 2, 3))
+                .modifier(Modifier::Reverse),
+        );
+
+        assert!(frame
+            .to_html()
+            .contains("color:#000000;background-color:#010203"));
+    }
+
+    #[test]
+    fn svg_export_draws_grid_aligned_backgrounds_and_text() {
+        let svg = sample_frame().to_svg();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"72\""));
+        assert!(
+            svg.contains("<rect x=\"27\" y=\"0\" width=\"27\" height=\"18\" fill=\"#00005f\"/>")
+        );
+        assert!(svg.contains("font-weight=\"bold\">ok</text>"));
+        assert!(svg.contains("text-decoration=\"line-through\""));
+        assert!(svg.contains("&lt;x&gt;"));
+        assert!(svg.contains("text-decoration-style:wavy"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Rect, Style};
+use crate::{
+    ui::{border_glyphs, BorderType},
+    ModifierSet, Rect, Style,
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Cell {
@@ -15,6 +18,57 @@ impl Default for Cell {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WriteMode {
+    #[default]
+    Replace,
+    Patch,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CursorShape {
+    #[default]
+    Block,
+    Bar,
+    Underline,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cursor {
+    pub x: u16,
+    pub y: u16,
+    pub shape: CursorShape,
+    pub blinking: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BlitOptions {
+    source_area: Option<Rect>,
+    transparent: bool,
+    patch: bool,
+}
+
+impl BlitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn source_area(mut self, area: Rect) -> Self {
+        self.source_area = Some(area);
+        self
+    }
+
+    pub fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    pub fn patch(mut self, patch: bool) -> Self {
+        self.patch = patch;
+        self
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Frame {
     width: u16,
@@ -23,6 +77,10 @@ pub struct Frame {
     clip: Rect,
     origin_x: u16,
     origin_y: u16,
+    write_mode: WriteMode,
+    cursor: Option<(u16, u16)>,
+    cursor_shape: CursorShape,
+    cursor_blinking: bool,
 }
 
 impl Frame {
@@ -35,6 +93,10 @@ impl Frame {
             clip: Rect::new(0, 0, width, height),
             origin_x: 0,
             origin_y: 0,
+            write_mode: WriteMode::Replace,
+            cursor: None,
+            cursor_shape: CursorShape::Block,
+            cursor_blinking: true,
         }
     }
 
@@ -43,6 +105,9 @@ impl Frame {
             cell.ch = ' ';
             cell.style = Style::default();
         }
+        self.cursor = None;
+        self.cursor_shape = CursorShape::Block;
+        self.cursor_blinking = true;
     }
 
     pub fn print(&mut self, x: u16, y: u16, text: &str) {
@@ -88,7 +153,115 @@ impl Frame {
 
             let idx = self.index(px as u16, global_y as u16);
             self.cells[idx].ch = ch;
-            self.cells[idx].style = style;
+            self.write_style(idx, style);
+        }
+    }
+
+    pub fn set_cell(&mut self, x: u16, y: u16, ch: char, style: Style) {
+        self.fill(Rect::new(x, y, 1, 1), ch, style);
+    }
+
+    pub fn fill(&mut self, area: Rect, ch: char, style: Style) {
+        let visible = self.visible_area(area);
+        for y in visible.y..visible.y + visible.height {
+            for x in visible.x..visible.x + visible.width {
+                let idx = self.index(x, y);
+                self.cells[idx].ch = ch;
+                self.write_style(idx, style);
+            }
+        }
+    }
+
+    pub fn set_style(&mut self, area: Rect, style: Style) {
+        let visible = self.visible_area(area);
+        for y in visible.y..visible.y + visible.height {
+            for x in visible.x..visible.x + visible.width {
+                let idx = self.index(x, y);
+                self.write_style(idx, style);
+            }
+        }
+    }
+
+    pub fn horizontal_line(
+        &mut self,
+        x: u16,
+        y: u16,
+        length: u16,
+        border_type: BorderType,
+        style: Style,
+    ) {
+        let glyphs = border_glyphs(border_type);
+        self.fill(Rect::new(x, y, length, 1), glyphs.horizontal, style);
+    }
+
+    pub fn vertical_line(
+        &mut self,
+        x: u16,
+        y: u16,
+        length: u16,
+        border_type: BorderType,
+        style: Style,
+    ) {
+        let glyphs = border_glyphs(border_type);
+        self.fill(Rect::new(x, y, 1, length), glyphs.vertical, style);
+    }
+
+    pub fn draw_box(&mut self, area: Rect, border_type: BorderType, style: Style) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let glyphs = border_glyphs(border_type);
+        let right = area.x.saturating_add(area.width - 1);
+        let bottom = area.y.saturating_add(area.height - 1);
+        let inner_width = area.width.saturating_sub(2);
+        let inner_height = area.height.saturating_sub(2);
+
+        let inner_x = area.x.saturating_add(1);
+        let inner_y = area.y.saturating_add(1);
+
+        self.horizontal_line(inner_x, area.y, inner_width, border_type, style);
+        self.horizontal_line(inner_x, bottom, inner_width, border_type, style);
+        self.vertical_line(area.x, inner_y, inner_height, border_type, style);
+        self.vertical_line(right, inner_y, inner_height, border_type, style);
+        self.set_cell(area.x, area.y, glyphs.top_left, style);
+        self.set_cell(right, area.y, glyphs.top_right, style);
+        self.set_cell(area.x, bottom, glyphs.bottom_left, style);
+        self.set_cell(right, bottom, glyphs.bottom_right, style);
+    }
+
+    pub fn blit(&mut self, source: &Frame, x: u16, y: u16) {
+        self.blit_with(source, x, y, BlitOptions::new());
+    }
+
+    pub fn blit_with(&mut self, source: &Frame, x: u16, y: u16, options: BlitOptions) {
+        let source_bounds = Rect::new(0, 0, source.width, source.height);
+        let source_area = options
+            .source_area
+            .map(|area| area.intersection(source_bounds))
+            .unwrap_or(source_bounds);
+        let target = self.visible_area(Rect::new(x, y, source_area.width, source_area.height));
+        let mode = if options.patch {
+            WriteMode::Patch
+        } else {
+            self.write_mode
+        };
+
+        let offset_x = self.origin_x as u32 + x as u32;
+        let offset_y = self.origin_y as u32 + y as u32;
+        for ty in target.y..target.y + target.height {
+            let sy = source_area.y + (ty as u32 - offset_y) as u16;
+            for tx in target.x..target.x + target.width {
+                let sx = source_area.x + (tx as u32 - offset_x) as u16;
+                let cell = source.cells[source.index(sx, sy)];
+                if options.transparent && cell == Cell::default() {
+                    continue;
+                }
+
+                let idx = self.index(tx, ty);
+                self.cells[idx].ch = cell.ch;
+                self.write_style_with(idx, cell.style, mode);
+            }
         }
     }
 
@@ -100,7 +273,7 @@ impl Frame {
             area.height,
         );
         let bounds = Rect::new(0, 0, self.width, self.height);
-        let next_clip = intersect_rects(intersect_rects(self.clip, local_area), bounds);
+        let next_clip = self.clip.intersection(local_area).intersection(bounds);
 
         let previous_clip = self.clip;
         let previous_origin_x = self.origin_x;
@@ -117,6 +290,50 @@ impl Frame {
         self.origin_y = previous_origin_y;
     }
 
+    pub fn with_write_mode(&mut self, mode: WriteMode, f: impl FnOnce(&mut Frame)) {
+        let previous_mode = self.write_mode;
+        self.write_mode = mode;
+
+        f(self);
+
+        self.write_mode = previous_mode;
+    }
+
+    pub fn set_write_mode(&mut self, mode: WriteMode) {
+        self.write_mode = mode;
+    }
+
+    pub fn write_mode(&self) -> WriteMode {
+        self.write_mode
+    }
+
+    pub fn set_cursor(&mut self, x: u16, y: u16) {
+        let visible = self.visible_area(Rect::new(x, y, 1, 1));
+        if visible.width == 0 || visible.height == 0 {
+            return;
+        }
+
+        self.cursor = Some((visible.x, visible.y));
+    }
+
+    pub fn set_cursor_shape(&mut self, shape: CursorShape, blinking: bool) {
+        self.cursor_shape = shape;
+        self.cursor_blinking = blinking;
+    }
+
+    pub fn hide_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    pub fn cursor(&self) -> Option<Cursor> {
+        self.cursor.map(|(x, y)| Cursor {
+            x,
+            y,
+            shape: self.cursor_shape,
+            blinking: self.cursor_blinking,
+        })
+    }
+
     pub fn width(&self) -> u16 {
         self.width
     }
@@ -133,6 +350,11 @@ impl Frame {
         Some(self.cells[self.index(x, y)].ch)
     }
 
+    #[cfg(test)]
+    pub(crate) fn row_text(&self, y: u16) -> String {
+        (0..self.width).filter_map(|x| self.char_at(x, y)).collect()
+    }
+
     pub fn style_at(&self, x: u16, y: u16) -> Option<Style> {
         if x >= self.width || y >= self.height {
             return None;
@@ -151,6 +373,10 @@ impl Frame {
         self.clip = Rect::new(0, 0, other.width, other.height);
         self.origin_x = 0;
         self.origin_y = 0;
+        self.write_mode = WriteMode::Replace;
+        self.cursor = other.cursor;
+        self.cursor_shape = other.cursor_shape;
+        self.cursor_blinking = other.cursor_blinking;
 
         if self.cells.len() != other.cells.len() {
             self.cells = vec![Cell::default(); other.cells.len()];
@@ -159,43 +385,42 @@ impl Frame {
         self.cells.clone_from_slice(other.cells());
     }
 
-    fn index(&self, x: u16, y: u16) -> usize {
-        y as usize * self.width as usize + x as usize
+    fn visible_area(&self, area: Rect) -> Rect {
+        let global = Rect::new(
+            self.origin_x.saturating_add(area.x),
+            self.origin_y.saturating_add(area.y),
+            area.width,
+            area.height,
+        );
+        let bounds = Rect::new(0, 0, self.width, self.height);
+        self.clip.intersection(global).intersection(bounds)
     }
-}
-
-fn intersect_rects(a: Rect, b: Rect) -> Rect {
-    let a_left = a.x as u32;
-    let a_top = a.y as u32;
-    let a_right = a_left + a.width as u32;
-    let a_bottom = a_top + a.height as u32;
 
-    let b_left = b.x as u32;
-    let b_top = b.y as u32;
-    let b_right = b_left + b.width as u32;
-    let b_bottom = b_top + b.height as u32;
-
-    let left = a_left.max(b_left);
-    let top = a_top.max(b_top);
-    let right = a_right.min(b_right);
-    let bottom = a_bottom.min(b_bottom);
+    fn write_style(&mut self, idx: usize, style: Style) {
+        self.write_style_with(idx, style, self.write_mode);
+    }
 
-    if right <= left || bottom <= top {
-        return Rect::new(left as u16, top as u16, 0, 0);
+    fn write_style_with(&mut self, idx: usize, style: Style, mode: WriteMode) {
+        let cell = &mut self.cells[idx];
+        let style = match mode {
+            WriteMode::Replace => style,
+            WriteMode::Patch => cell.style.patch(style),
+        };
+        cell.style = Style {
+            removed_modifiers: ModifierSet::default(),
+            ..style
+        };
     }
 
-    Rect::new(
-        left as u16,
-        top as u16,
-        (right - left) as u16,
-        (bottom - top) as u16,
-    )
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Frame;
-    use crate::{Color, Modifier, Rect, Style};
+    use super::{BlitOptions, Cursor, CursorShape, Frame, WriteMode};
+    use crate::{BorderType, Color, Modifier, Rect, Style};
 
     #[test]
     fn print_stops_at_frame_right_edge() {
@@ -293,4 +518,283 @@ mod tests {
         assert_eq!(frame.style_at(3, 0), Some(style));
         assert_eq!(frame.style_at(2, 0), Some(Style::default()));
     }
+
+    #[test]
+    fn patch_mode_keeps_existing_background() {
+        let mut frame = Frame::new(4, 1);
+        frame.print_styled(0, 0, "    ", Style::new().bg(Color::Ansi(17)));
+
+        frame.with_write_mode(WriteMode::Patch, |f| {
+            f.print_styled(0, 0, "ab", Style::new().fg(Color::Ansi(230)));
+        });
+
+        assert_eq!(frame.char_at(0, 0), Some('a'));
+        assert_eq!(
+            frame.style_at(0, 0),
+            Some(Style::new().fg(Color::Ansi(230)).bg(Color::Ansi(17)))
+        );
+        assert_eq!(frame.style_at(2, 0), Some(Style::new().bg(Color::Ansi(17))));
+    }
+
+    #[test]
+    fn patch_mode_removes_modifiers_explicitly() {
+        let mut frame = Frame::new(2, 1);
+        frame.print_styled(0, 0, "xx", Style::new().modifier(Modifier::Bold));
+
+        frame.set_write_mode(WriteMode::Patch);
+        frame.print_styled(0, 0, "y", Style::new().remove(Modifier::Bold));
+
+        let style = frame.style_at(0, 0).expect("cell exists");
+        assert!(!style.modifiers.contains(Modifier::Bold));
+        assert!(frame
+            .style_at(1, 0)
+            .expect("cell exists")
+            .modifiers
+            .contains(Modifier::Bold));
+    }
+
+    #[test]
+    fn draw_box_at_max_coordinates_does_not_overflow() {
+        let mut frame = Frame::new(4, 4);
+        frame.draw_box(
+            Rect::new(u16::MAX, u16::MAX, 3, 3),
+            BorderType::Unicode,
+            Style::default(),
+        );
+        frame.draw_box(
+            Rect::new(u16::MAX, 0, 1, 1),
+            BorderType::Ascii,
+            Style::default(),
+        );
+
+        assert_eq!(frame.char_at(0, 0), Some(' '));
+    }
+
+    #[test]
+    fn stored_styles_drop_removed_modifiers() {
+        let mut frame = Frame::new(2, 1);
+        frame.print_styled(0, 0, "x", Style::new().modifier(Modifier::Bold));
+        frame.with_write_mode(WriteMode::Patch, |f| {
+            f.print_styled(0, 0, "x", Style::new().remove(Modifier::Bold));
+        });
+        frame.print_styled(1, 0, "x", Style::new().remove(Modifier::Italic));
+
+        assert_eq!(frame.style_at(0, 0), Some(Style::default()));
+        assert_eq!(frame.style_at(1, 0), Some(Style::default()));
+    }
+
+    #[test]
+    fn with_write_mode_restores_previous_mode() {
+        let mut frame = Frame::new(2, 1);
+
+        frame.with_write_mode(WriteMode::Patch, |f| {
+            assert_eq!(f.write_mode(), WriteMode::Patch);
+        });
+
+        assert_eq!(frame.write_mode(), WriteMode::Replace);
+    }
+
+    #[test]
+    fn set_cell_respects_origin_and_clip() {
+        let mut frame = Frame::new(6, 2);
+        let style = Style::new().fg(Color::Ansi(9));
+
+        frame.render_in(Rect::new(2, 1, 2, 1), |f| {
+            f.set_cell(1, 0, 'x', style);
+            f.set_cell(2, 0, 'y', style);
+        });
+
+        assert_eq!(frame.char_at(3, 1), Some('x'));
+        assert_eq!(frame.style_at(3, 1), Some(style));
+        assert_eq!(frame.char_at(4, 1), Some(' '));
+    }
+
+    #[test]
+    fn fill_clips_area_to_render_region() {
+        let mut frame = Frame::new(5, 3);
+        let style = Style::new().bg(Color::Ansi(17));
+
+        frame.render_in(Rect::new(1, 1, 3, 2), |f| {
+            f.fill(Rect::new(1, 0, 10, 10), '#', style);
+        });
+
+        assert_eq!(frame.row_text(0), "     ");
+        assert_eq!(frame.row_text(1), "  ## ");
+        assert_eq!(frame.row_text(2), "  ## ");
+        assert_eq!(frame.style_at(2, 1), Some(style));
+    }
+
+    #[test]
+    fn set_style_keeps_characters() {
+        let mut frame = Frame::new(4, 1);
+        frame.print(0, 0, "abcd");
+        let style = Style::new().modifier(Modifier::Reverse);
+
+        frame.set_style(Rect::new(1, 0, 2, 1), style);
+
+        assert_eq!(frame.row_text(0), "abcd");
+        assert_eq!(frame.style_at(0, 0), Some(Style::default()));
+        assert_eq!(frame.style_at(1, 0), Some(style));
+        assert_eq!(frame.style_at(2, 0), Some(style));
+    }
+
+    #[test]
+    fn set_style_patches_in_patch_mode() {
+        let mut frame = Frame::new(2, 1);
+        frame.print_styled(0, 0, "ab", Style::new().fg(Color::Ansi(3)));
+
+        frame.with_write_mode(WriteMode::Patch, |f| {
+            f.set_style(Rect::new(0, 0, 2, 1), Style::new().bg(Color::Ansi(4)));
+        });
+
+        assert_eq!(
+            frame.style_at(1, 0),
+            Some(Style::new().fg(Color::Ansi(3)).bg(Color::Ansi(4)))
+        );
+    }
+
+    #[test]
+    fn lines_use_border_type_glyphs() {
+        let mut frame = Frame::new(4, 3);
+
+        frame.horizontal_line(0, 0, 3, BorderType::Unicode, Style::default());
+        frame.vertical_line(3, 0, 3, BorderType::Ascii, Style::default());
+
+        assert_eq!(frame.row_text(0), "───|");
+        assert_eq!(frame.row_text(1), "   |");
+        assert_eq!(frame.row_text(2), "   |");
+    }
+
+    #[test]
+    fn draw_box_draws_corners_and_edges() {
+        let mut frame = Frame::new(5, 4);
+
+        frame.draw_box(Rect::new(0, 0, 4, 3), BorderType::Unicode, Style::default());
+        frame.render_in(Rect::new(4, 3, 1, 1), |f| {
+            f.draw_box(Rect::new(0, 0, 3, 3), BorderType::Ascii, Style::default());
+        });
+
+        assert_eq!(frame.row_text(0), "┌──┐ ");
+        assert_eq!(frame.row_text(1), "│  │ ");
+        assert_eq!(frame.row_text(2), "└──┘ ");
+        assert_eq!(frame.row_text(3), "    +");
+    }
+
+    fn offscreen_panel() -> Frame {
+        let mut panel = Frame::new(4, 2);
+        panel.print_styled(0, 0, "ab", Style::new().fg(Color::Ansi(2)));
+        panel.print(1, 1, "cd");
+        panel
+    }
+
+    #[test]
+    fn blit_copies_cells_at_offset() {
+        let mut frame = Frame::new(6, 3);
+        frame.fill(Rect::new(0, 0, 6, 3), '.', Style::default());
+
+        frame.blit(&offscreen_panel(), 1, 1);
+
+        assert_eq!(frame.row_text(0), "......");
+        assert_eq!(frame.row_text(1), ".ab  .");
+        assert_eq!(frame.row_text(2), ". cd .");
+        assert_eq!(frame.style_at(1, 1), Some(Style::new().fg(Color::Ansi(2))));
+    }
+
+    #[test]
+    fn blit_respects_destination_clip_and_origin() {
+        let mut frame = Frame::new(6, 3);
+
+        frame.render_in(Rect::new(2, 1, 2, 1), |f| {
+            f.blit(&offscreen_panel(), 1, 0);
+        });
+
+        assert_eq!(frame.row_text(0), "      ");
+        assert_eq!(frame.row_text(1), "   a  ");
+        assert_eq!(frame.row_text(2), "      ");
+    }
+
+    #[test]
+    fn blit_with_source_area_crops_source() {
+        let mut frame = Frame::new(4, 2);
+
+        frame.blit_with(
+            &offscreen_panel(),
+            0,
+            0,
+            BlitOptions::new().source_area(Rect::new(1, 0, 2, 9)),
+        );
+
+        assert_eq!(frame.row_text(0), "b   ");
+        assert_eq!(frame.row_text(1), "cd  ");
+    }
+
+    #[test]
+    fn blit_transparent_skips_untouched_cells() {
+        let mut frame = Frame::new(4, 2);
+        frame.fill(Rect::new(0, 0, 4, 2), '.', Style::default());
+
+        frame.blit_with(
+            &offscreen_panel(),
+            0,
+            0,
+            BlitOptions::new().transparent(true),
+        );
+
+        assert_eq!(frame.row_text(0), "ab..");
+        assert_eq!(frame.row_text(1), ".cd.");
+    }
+
+    #[test]
+    fn blit_patch_keeps_destination_background() {
+        let mut frame = Frame::new(4, 2);
+        frame.fill(Rect::new(0, 0, 4, 2), ' ', Style::new().bg(Color::Ansi(17)));
+
+        frame.blit_with(&offscreen_panel(), 0, 0, BlitOptions::new().patch(true));
+
+        assert_eq!(
+            frame.style_at(0, 0),
+            Some(Style::new().fg(Color::Ansi(2)).bg(Color::Ansi(17)))
+        );
+        assert_eq!(frame.style_at(3, 1), Some(Style::new().bg(Color::Ansi(17))));
+    }
+
+    #[test]
+    fn set_cursor_is_origin_aware_and_clipped() {
+        let mut frame = Frame::new(8, 4);
+
+        frame.render_in(Rect::new(2, 1, 3, 2), |f| {
+            f.set_cursor(1, 1);
+            f.set_cursor(5, 0);
+        });
+
+        assert_eq!(
+            frame.cursor(),
+            Some(Cursor {
+                x: 3,
+                y: 2,
+                shape: CursorShape::Block,
+                blinking: true,
+            })
+        );
+    }
+
+    #[test]
+    fn clear_hides_cursor_and_resets_shape() {
+        let mut frame = Frame::new(4, 1);
+        frame.set_cursor(1, 0);
+        frame.set_cursor_shape(CursorShape::Bar, false);
+        assert_eq!(
+            frame.cursor().map(|cursor| (cursor.shape, cursor.blinking)),
+            Some((CursorShape::Bar, false))
+        );
+
+        frame.clear();
+        assert_eq!(frame.cursor(), None);
+
+        frame.set_cursor(0, 0);
+        assert_eq!(
+            frame.cursor().map(|cursor| cursor.shape),
+            Some(CursorShape::Block)
+        );
+    }
 }
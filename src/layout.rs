@@ -1,3 +1,5 @@
+use crate::Padding;
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Rect {
     pub x: u16,
@@ -35,11 +37,130 @@ impl Rect {
             Rect::new(self.x.saturating_add(left_w), self.y, right_w, self.height),
         )
     }
+
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    pub fn area(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        let (x, y) = (x as u32, y as u32);
+        x >= self.x as u32
+            && x < self.x as u32 + self.width as u32
+            && y >= self.y as u32
+            && y < self.y as u32 + self.height as u32
+    }
+
+    pub fn intersection(&self, other: Rect) -> Rect {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = (self.x as u32 + self.width as u32).min(other.x as u32 + other.width as u32);
+        let bottom = (self.y as u32 + self.height as u32).min(other.y as u32 + other.height as u32);
+
+        if right <= left as u32 || bottom <= top as u32 {
+            return Rect::new(left, top, 0, 0);
+        }
+
+        Rect::new(
+            left,
+            top,
+            (right - left as u32) as u16,
+            (bottom - top as u32) as u16,
+        )
+    }
+
+    pub fn union(&self, other: Rect) -> Rect {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+
+        let left = self.x.min(other.x);
+        let top = self.y.min(other.y);
+        let right = (self.x as u32 + self.width as u32).max(other.x as u32 + other.width as u32);
+        let bottom = (self.y as u32 + self.height as u32).max(other.y as u32 + other.height as u32);
+
+        Rect::new(
+            left,
+            top,
+            (right - left as u32).min(u16::MAX as u32) as u16,
+            (bottom - top as u32).min(u16::MAX as u32) as u16,
+        )
+    }
+
+    pub fn inner(&self, padding: Padding) -> Rect {
+        padding.apply(*self)
+    }
+
+    pub fn offset(&self, dx: i32, dy: i32) -> Rect {
+        let shift =
+            |value: u16, delta: i32| (value as i64 + delta as i64).clamp(0, u16::MAX as i64) as u16;
+        Rect::new(
+            shift(self.x, dx),
+            shift(self.y, dy),
+            self.width,
+            self.height,
+        )
+    }
+
+    pub fn clamp_within(&self, bounds: Rect) -> Rect {
+        let width = self.width.min(bounds.width);
+        let height = self.height.min(bounds.height);
+        let x = self
+            .x
+            .clamp(bounds.x, bounds.x.saturating_add(bounds.width - width));
+        let y = self
+            .y
+            .clamp(bounds.y, bounds.y.saturating_add(bounds.height - height));
+
+        Rect::new(x, y, width, height)
+    }
+
+    pub fn centered(&self, width: u16, height: u16) -> Rect {
+        let width = width.min(self.width);
+        let height = height.min(self.height);
+
+        Rect::new(
+            self.x.saturating_add((self.width - width) / 2),
+            self.y.saturating_add((self.height - height) / 2),
+            width,
+            height,
+        )
+    }
+
+    pub fn centered_percent(&self, percent_x: u8, percent_y: u8) -> Rect {
+        let scale = |total: u16, percent: u8| (total as u32 * percent.min(100) as u32 / 100) as u16;
+        self.centered(scale(self.width, percent_x), scale(self.height, percent_y))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Rect> {
+        let rect = *self;
+        (rect.y..rect.bottom()).map(move |y| Rect::new(rect.x, y, rect.width, 1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Rect> {
+        let rect = *self;
+        (rect.x..rect.right()).map(move |x| Rect::new(x, rect.y, 1, rect.height))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Rect;
+    use crate::Padding;
 
     #[test]
     fn split_vertical_clamps_to_height() {
@@ -77,4 +198,100 @@ mod tests {
         assert_eq!(right, Rect::new(4, 1, 5, 2));
         assert_eq!(left.width + right.width, root.width);
     }
+
+    #[test]
+    fn contains_and_is_empty_respect_edges() {
+        let rect = Rect::new(2, 3, 4, 2);
+
+        assert!(rect.contains(2, 3));
+        assert!(rect.contains(5, 4));
+        assert!(!rect.contains(6, 4));
+        assert!(!rect.contains(5, 5));
+        assert!(!rect.contains(1, 3));
+        assert!(!Rect::new(2, 3, 0, 2).contains(2, 3));
+        assert!(Rect::new(2, 3, 0, 2).is_empty());
+        assert!(!rect.is_empty());
+        assert!(Rect::new(u16::MAX, u16::MAX, 10, 10).contains(u16::MAX, u16::MAX));
+    }
+
+    #[test]
+    fn intersection_and_union_cover_overlap_and_disjoint_cases() {
+        let a = Rect::new(0, 0, 10, 6);
+        let b = Rect::new(6, 4, 10, 10);
+
+        assert_eq!(a.intersection(b), Rect::new(6, 4, 4, 2));
+        assert_eq!(a.union(b), Rect::new(0, 0, 16, 14));
+        assert!(a.intersection(Rect::new(20, 20, 2, 2)).is_empty());
+        assert_eq!(a.union(Rect::new(50, 50, 0, 0)), a);
+        assert_eq!(Rect::new(50, 50, 0, 3).union(b), b);
+    }
+
+    #[test]
+    fn union_and_intersection_are_overflow_safe() {
+        let far = Rect::new(u16::MAX - 1, u16::MAX - 1, u16::MAX, u16::MAX);
+        let origin = Rect::new(0, 0, 4, 4);
+
+        assert_eq!(origin.union(far), Rect::new(0, 0, u16::MAX, u16::MAX));
+        assert_eq!(
+            far.intersection(Rect::new(u16::MAX - 3, u16::MAX - 3, 10, 10)),
+            Rect::new(u16::MAX - 1, u16::MAX - 1, 8, 8)
+        );
+        assert_eq!(far.right(), u16::MAX);
+        assert_eq!(far.area(), u16::MAX as u32 * u16::MAX as u32);
+    }
+
+    #[test]
+    fn inner_offset_and_clamp_within() {
+        let rect = Rect::new(2, 2, 10, 6);
+
+        assert_eq!(rect.inner(Padding::symmetric(1, 2)), Rect::new(4, 3, 6, 4));
+        assert_eq!(rect.offset(-5, 3), Rect::new(0, 5, 10, 6));
+        assert_eq!(rect.offset(i32::MAX, 0), Rect::new(u16::MAX, 2, 10, 6));
+
+        let bounds = Rect::new(0, 0, 20, 10);
+        assert_eq!(
+            Rect::new(15, 8, 10, 4).clamp_within(bounds),
+            Rect::new(10, 6, 10, 4)
+        );
+        assert_eq!(
+            Rect::new(5, 5, 30, 30).clamp_within(bounds),
+            Rect::new(0, 0, 20, 10)
+        );
+        assert_eq!(rect.clamp_within(bounds), rect);
+    }
+
+    #[test]
+    fn centered_and_percent_popups_stay_inside() {
+        let screen = Rect::new(0, 0, 80, 24);
+
+        assert_eq!(screen.centered(40, 10), Rect::new(20, 7, 40, 10));
+        assert_eq!(screen.centered(200, 200), screen);
+        assert_eq!(screen.centered_percent(50, 50), Rect::new(20, 6, 40, 12));
+        assert_eq!(screen.centered_percent(150, 0), Rect::new(0, 12, 80, 0));
+        assert_eq!(
+            Rect::new(10, 5, 21, 9).centered(5, 3),
+            Rect::new(18, 8, 5, 3)
+        );
+    }
+
+    #[test]
+    fn rows_and_columns_iterate_unit_strips() {
+        let rect = Rect::new(3, 1, 2, 3);
+
+        let rows: Vec<_> = rect.rows().collect();
+        let columns: Vec<_> = rect.columns().collect();
+
+        assert_eq!(
+            rows,
+            [
+                Rect::new(3, 1, 2, 1),
+                Rect::new(3, 2, 2, 1),
+                Rect::new(3, 3, 2, 1)
+            ]
+        );
+        assert_eq!(columns, [Rect::new(3, 1, 1, 3), Rect::new(4, 1, 1, 3)]);
+        assert_eq!(Rect::new(0, u16::MAX - 1, 1, 5).rows().count(), 1);
+        assert_eq!(Rect::new(0, 0, 0, 5).rows().count(), 5);
+        assert_eq!(Rect::new(0, 0, 0, 5).columns().count(), 0);
+    }
 }
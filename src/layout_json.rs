@@ -0,0 +1,535 @@
+use std::{fmt, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ui::{NodeKind, Spacing};
+use crate::{
+    Breakpoint, Constraint, CrossAlign, Direction, GridCell, Justify, LayoutNode, Padding, Slot,
+};
+
+impl LayoutNode {
+    pub fn from_json_str(input: &str) -> Result<Self, LayoutError> {
+        let spec: NodeSpec = serde_json::from_str(input)
+            .map_err(|err| LayoutError::Parse(format!("invalid layout JSON: {err}")))?;
+        spec.into_node("")
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, LayoutError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path)
+            .map_err(|err| LayoutError::Io(format!("failed to read {}: {err}", path.display())))?;
+        Self::from_json_str(&data)
+    }
+
+    pub fn to_json_string(&self) -> Result<String, LayoutError> {
+        let spec = NodeSpec::from_node(self);
+        spec.clone().into_node("")?;
+        serde_json::to_string_pretty(&spec)
+            .map_err(|err| LayoutError::Parse(format!("failed to encode layout: {err}")))
+    }
+}
+
+#[derive(Debug)]
+pub enum LayoutError {
+    Io(String),
+    Parse(String),
+    Invalid(String),
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::Io(message) => write!(f, "{message}"),
+            LayoutError::Parse(message) => write!(f, "{message}"),
+            LayoutError::Invalid(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct NodeSpec {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    padding: Option<PaddingSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    split: Option<SplitSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    grid: Option<GridSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    responsive: Option<Vec<BreakpointSpec>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct PaddingSpec {
+    #[serde(default)]
+    top: u16,
+    #[serde(default)]
+    right: u16,
+    #[serde(default)]
+    bottom: u16,
+    #[serde(default)]
+    left: u16,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct SplitSpec {
+    direction: Direction,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    gap: u16,
+    #[serde(default, skip_serializing_if = "is_default")]
+    justify: Justify,
+    #[serde(default, skip_serializing_if = "is_default")]
+    align: CrossAlign,
+    children: Vec<SlotSpec>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct SlotSpec {
+    constraint: Constraint,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cross_size: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    align: Option<CrossAlign>,
+    node: NodeSpec,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct GridSpec {
+    rows: Vec<Constraint>,
+    columns: Vec<Constraint>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    row_gap: u16,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    column_gap: u16,
+    cells: Vec<CellSpec>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct CellSpec {
+    row: u16,
+    column: u16,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    row_span: u16,
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    column_span: u16,
+    node: NodeSpec,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct BreakpointSpec {
+    #[serde(default, skip_serializing_if = "is_zero")]
+    min_width: u16,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    min_height: u16,
+    node: NodeSpec,
+}
+
+impl NodeSpec {
+    fn into_node(self, parent: &str) -> Result<LayoutNode, LayoutError> {
+        let path = if parent.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{parent}/{}", self.name)
+        };
+        if self.name.is_empty() {
+            return Err(LayoutError::Invalid(if parent.is_empty() {
+                "root layout node has an empty name".to_string()
+            } else {
+                format!("layout node under `{parent}` has an empty name")
+            }));
+        }
+        if self.name.contains('/') {
+            return Err(LayoutError::Invalid(format!(
+                "layout node name `{}` must not contain `/`",
+                self.name
+            )));
+        }
+
+        let padding = self.padding.unwrap_or_default();
+        let node = match (self.split, self.grid, self.responsive) {
+            (Some(split), None, None) => {
+                let mut children = Vec::with_capacity(split.children.len());
+                for slot in split.children {
+                    children.push(slot.into_slot(&path)?);
+                }
+                LayoutNode::split(self.name, split.direction, children)
+                    .with_gap(split.gap)
+                    .with_justify(split.justify)
+                    .with_align(split.align)
+            }
+            (None, Some(grid), None) => {
+                for constraint in grid.rows.iter().chain(&grid.columns) {
+                    validate_constraint(*constraint, &path)?;
+                }
+                let mut cells = Vec::with_capacity(grid.cells.len());
+                for cell in grid.cells {
+                    cells.push(cell.into_cell(&path, grid.rows.len(), grid.columns.len())?);
+                }
+                LayoutNode::grid(self.name, grid.rows, grid.columns, cells)
+                    .with_row_gap(grid.row_gap)
+                    .with_column_gap(grid.column_gap)
+            }
+            (None, None, Some(variants)) => {
+                if variants.is_empty() {
+                    return Err(LayoutError::Invalid(format!(
+                        "responsive node `{path}` needs at least one variant"
+                    )));
+                }
+                let mut breakpoints = Vec::with_capacity(variants.len());
+                for variant in variants {
+                    breakpoints.push(
+                        Breakpoint::new(variant.node.into_node(&path)?)
+                            .min_width(variant.min_width)
+                            .min_height(variant.min_height),
+                    );
+                }
+                LayoutNode::responsive(self.name, breakpoints)
+            }
+            (None, None, None) => LayoutNode::leaf(self.name),
+            _ => {
+                return Err(LayoutError::Invalid(format!(
+                    "layout node `{path}` must set at most one of split, grid, or responsive"
+                )))
+            }
+        };
+
+        Ok(node.with_padding(Padding {
+            top: padding.top,
+            right: padding.right,
+            bottom: padding.bottom,
+            left: padding.left,
+        }))
+    }
+
+    fn from_node(node: &LayoutNode) -> Self {
+        let padding = node.padding;
+        let padding = (padding != Padding::default()).then_some(PaddingSpec {
+            top: padding.top,
+            right: padding.right,
+            bottom: padding.bottom,
+            left: padding.left,
+        });
+
+        let mut spec = Self {
+            name: node.name.clone(),
+            padding,
+            split: None,
+            grid: None,
+            responsive: None,
+        };
+        match &node.kind {
+            NodeKind::Leaf => {}
+            NodeKind::Split {
+                direction,
+                children,
+                spacing:
+                    Spacing {
+                        gap,
+                        justify,
+                        align,
+                    },
+            } => {
+                spec.split = Some(SplitSpec {
+                    direction: *direction,
+                    gap: *gap,
+                    justify: *justify,
+                    align: *align,
+                    children: children
+                        .iter()
+                        .map(|slot| SlotSpec {
+                            constraint: slot.constraint,
+                            min: slot.min,
+                            max: slot.max,
+                            cross_size: slot.cross_size,
+                            align: slot.align,
+                            node: Self::from_node(&slot.node),
+                        })
+                        .collect(),
+                });
+            }
+            NodeKind::Grid {
+                rows,
+                columns,
+                cells,
+                row_gap,
+                column_gap,
+            } => {
+                spec.grid = Some(GridSpec {
+                    rows: rows.clone(),
+                    columns: columns.clone(),
+                    row_gap: *row_gap,
+                    column_gap: *column_gap,
+                    cells: cells
+                        .iter()
+                        .map(|cell| CellSpec {
+                            row: cell.row,
+                            column: cell.column,
+                            row_span: cell.row_span,
+                            column_span: cell.column_span,
+                            node: Self::from_node(&cell.node),
+                        })
+                        .collect(),
+                });
+            }
+            NodeKind::Responsive { variants } => {
+                spec.responsive = Some(
+                    variants
+                        .iter()
+                        .map(|variant| BreakpointSpec {
+                            min_width: variant.min_width,
+                            min_height: variant.min_height,
+                            node: Self::from_node(&variant.node),
+                        })
+                        .collect(),
+                );
+            }
+        }
+
+        spec
+    }
+}
+
+impl SlotSpec {
+    fn into_slot(self, parent: &str) -> Result<Slot, LayoutError> {
+        validate_constraint(self.constraint, parent)?;
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return Err(LayoutError::Invalid(format!(
+                    "slot `{}` in `{parent}` has min {min} greater than max {max}",
+                    self.node.name
+                )));
+            }
+        }
+
+        let mut slot = Slot::new(self.constraint, self.node.into_node(parent)?);
+        slot.min = self.min;
+        slot.max = self.max;
+        slot.cross_size = self.cross_size;
+        slot.align = self.align;
+        Ok(slot)
+    }
+}
+
+impl CellSpec {
+    fn into_cell(self, parent: &str, rows: usize, columns: usize) -> Result<GridCell, LayoutError> {
+        let fits =
+            |start: u16, span: u16, len: usize| span > 0 && start as usize + span as usize <= len;
+        if !fits(self.row, self.row_span, rows) || !fits(self.column, self.column_span, columns) {
+            return Err(LayoutError::Invalid(format!(
+                "grid cell `{}` in `{parent}` does not fit a {rows}x{columns} grid",
+                self.node.name
+            )));
+        }
+
+        Ok(
+            GridCell::new(self.row, self.column, self.node.into_node(parent)?)
+                .row_span(self.row_span)
+                .column_span(self.column_span),
+        )
+    }
+}
+
+fn validate_constraint(constraint: Constraint, path: &str) -> Result<(), LayoutError> {
+    match constraint {
+        Constraint::Percent(value) if value > 100 => Err(LayoutError::Invalid(format!(
+            "percent constraint {value} in `{path}` must be at most 100"
+        ))),
+        Constraint::Ratio(num, den) if den == 0 || num > den => Err(LayoutError::Invalid(format!(
+            "ratio constraint {num}/{den} in `{path}` must be between 0 and 1"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn one() -> u16 {
+    1
+}
+
+fn is_one(value: &u16) -> bool {
+    *value == 1
+}
+
+fn is_zero(value: &u16) -> bool {
+    *value == 0
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Constraint, Direction, GridCell, LayoutNode, Padding, Rect, Slot};
+
+    const CONSOLE: &str = r#"{
+        "name": "root",
+        "split": {
+            "direction": "vertical",
+            "children": [
+                { "constraint": { "fixed": 3 }, "node": { "name": "header", "padding": { "left": 2, "right": 2 } } },
+                {
+                    "constraint": { "fill": 1 },
+                    "node": {
+                        "name": "body",
+                        "split": {
+                            "direction": "horizontal",
+                            "gap": 1,
+                            "children": [
+                                { "constraint": { "percent": 30 }, "min": 20, "max": 50, "node": { "name": "sidebar" } },
+                                { "constraint": { "fill": 1 }, "node": { "name": "content" } }
+                            ]
+                        }
+                    }
+                },
+                { "constraint": { "fixed": 1 }, "node": { "name": "footer" } }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn parses_split_tree_and_resolves_like_code() {
+        let layout = LayoutNode::from_json_str(CONSOLE).expect("layout parses");
+        let resolved = layout.resolve(Rect::new(0, 0, 100, 30));
+
+        assert_eq!(resolved.area("header"), Some(Rect::new(2, 0, 96, 3)));
+        assert_eq!(resolved.area("sidebar"), Some(Rect::new(0, 3, 29, 26)));
+        assert_eq!(resolved.area("content"), Some(Rect::new(30, 3, 70, 26)));
+        assert_eq!(resolved.area("footer"), Some(Rect::new(0, 29, 100, 1)));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Horizontal,
+            [
+                Slot::new(Constraint::Ratio(1, 3), LayoutNode::leaf("a")).cross_size(2),
+                Slot::new(
+                    Constraint::Fill(2),
+                    LayoutNode::grid(
+                        "grid",
+                        [Constraint::Fixed(1), Constraint::Content],
+                        [Constraint::Min(4), Constraint::Max(8)],
+                        [GridCell::new(0, 0, LayoutNode::leaf("cell")).column_span(2)],
+                    )
+                    .with_row_gap(1),
+                ),
+            ],
+        )
+        .with_padding(Padding::all(1));
+
+        let json = layout.to_json_string().expect("layout encodes");
+        let decoded = LayoutNode::from_json_str(&json).expect("layout decodes");
+
+        assert_eq!(decoded.to_json_string().expect("layout encodes"), json);
+        assert!(json.contains("\"content\""));
+        let area = Rect::new(0, 0, 40, 12);
+        assert_eq!(decoded.resolve(area).zones(), layout.resolve(area).zones());
+    }
+
+    #[test]
+    fn parses_responsive_variants() {
+        let layout = LayoutNode::from_json_str(
+            r#"{ "name": "root", "responsive": [
+                { "min_width": 100, "node": { "name": "wide" } },
+                { "node": { "name": "narrow" } }
+            ] }"#,
+        )
+        .expect("layout parses");
+
+        let resolved = layout.resolve(Rect::new(0, 0, 80, 24));
+
+        assert_eq!(resolved.area("narrow"), Some(Rect::new(0, 0, 80, 24)));
+        assert!(resolved.is_hidden("wide"));
+        let json = layout.to_json_string().expect("layout encodes");
+        assert!(json.contains("\"min_width\": 100"));
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let err = LayoutNode::from_json_str(r#"{ "name": "root", "colour": "red" }"#)
+            .expect_err("unknown field should fail");
+
+        assert!(err.to_string().contains("colour"));
+    }
+
+    #[test]
+    fn rejects_invalid_definitions() {
+        let cases = [
+            r#"{ "name": "" }"#,
+            r#"{ "name": "x", "split": { "direction": "vertical", "children": [] }, "grid": { "rows": [], "columns": [], "cells": [] } }"#,
+            r#"{ "name": "x", "split": { "direction": "vertical", "children": [ { "constraint": { "percent": 120 }, "node": { "name": "y" } } ] } }"#,
+            r#"{ "name": "x", "split": { "direction": "vertical", "children": [ { "constraint": { "ratio": [1, 0] }, "node": { "name": "y" } } ] } }"#,
+            r#"{ "name": "x", "split": { "direction": "vertical", "children": [ { "constraint": { "fill": 1 }, "min": 5, "max": 2, "node": { "name": "y" } } ] } }"#,
+            r#"{ "name": "x", "responsive": [] }"#,
+            r#"{ "name": "a/b" }"#,
+            r#"{ "name": "x", "grid": { "rows": [ { "fill": 1 } ], "columns": [ { "fill": 1 } ], "cells": [ { "row": 0, "column": 0, "column_span": 2, "node": { "name": "y" } } ] } }"#,
+        ];
+
+        for case in cases {
+            let err = LayoutNode::from_json_str(case).expect_err("definition should be rejected");
+            assert!(
+                matches!(err, super::LayoutError::Invalid(_)),
+                "unexpected error for {case}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn refuses_to_encode_layouts_it_would_reject() {
+        let bounds = LayoutNode::split(
+            "root",
+            Direction::Vertical,
+            [Slot::new(Constraint::Fill(1), LayoutNode::leaf("a"))
+                .min(5)
+                .max(2)],
+        );
+        let grid = LayoutNode::grid(
+            "root",
+            [Constraint::Fill(1)],
+            [Constraint::Fill(1)],
+            [GridCell::new(0, 1, LayoutNode::leaf("cell"))],
+        );
+
+        for layout in [bounds, grid] {
+            assert!(matches!(
+                layout.to_json_string(),
+                Err(super::LayoutError::Invalid(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn names_the_root_when_its_name_is_empty() {
+        let err = LayoutNode::from_json_str(r#"{ "name": "" }"#).expect_err("empty root name");
+
+        assert_eq!(err.to_string(), "root layout node has an empty name");
+    }
+
+    #[test]
+    fn reports_node_path_in_errors() {
+        let err = LayoutNode::from_json_str(
+            r#"{ "name": "root", "split": { "direction": "vertical", "children": [
+                { "constraint": { "fill": 1 }, "node": { "name": "body", "split": { "direction": "horizontal", "children": [
+                    { "constraint": { "percent": 101 }, "node": { "name": "side" } }
+                ] } } }
+            ] } }"#,
+        )
+        .expect_err("percent over 100 should fail");
+
+        assert!(err.to_string().contains("`root/body`"));
+    }
+}
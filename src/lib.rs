@@ -1,30 +1,49 @@
 pub mod app;
 pub mod backend;
+pub mod cast;
 pub mod command;
 pub mod component;
+pub mod debug;
 pub mod event;
+mod export;
 pub mod frame;
 pub mod layout;
+mod layout_json;
 pub mod runtime;
+pub mod scroll;
+pub mod session;
+pub mod split_pane;
 pub mod style;
 pub mod theme;
 pub mod ui;
 
 pub use app::App;
+pub use backend::ColorDepth;
+pub use cast::{Cast, CastError, CastRecorder};
 pub use command::Command;
 pub use component::{update_child, Component};
+pub use debug::{
+    debug_keys, layout_debug_keys, render_layout_debug, DebugMsg, Debugger, HistoryEntry,
+    LayoutDebugMsg, LayoutDebugger,
+};
 pub use event::Event;
-pub use frame::Frame;
+pub use frame::{BlitOptions, Cursor, CursorShape, Frame, WriteMode};
 pub use layout::Rect;
+pub use layout_json::LayoutError;
 pub use runtime::run;
 pub use runtime::run_with_events;
-pub use style::{Color, Modifier, ModifierSet, Style};
+pub use runtime::{replay_events, run_with_options, RuntimeOptions};
+pub use scroll::{ScrollView, ScrollViewMsg, Scrollbar, ScrollbarOrientation, ScrollbarStyle};
+pub use session::{EventLog, EventLogError, EventRecorder, RecordedEvent};
+pub use split_pane::{SplitPane, SplitPaneMsg, SplitPaneStyle};
+pub use style::{Color, Modifier, ModifierSet, ParseColorError, Style};
 pub use theme::{Theme, ThemeError};
 pub use ui::{
-    apply_input_edit, Alignment, Block, BorderType, Borders, Checkbox, CheckboxStyle, Constraint,
-    Direction, FormField, FormFieldStyle, Input, InputEdit, InputStyle, LayoutNode, List,
-    ListStyle, MultiSelect, MultiSelectStyle, Padding, Panel, PanelStyle, Paragraph, ProgressBar,
-    ProgressBarStyle, RadioGroup, RadioGroupStyle, ResolvedLayout, Select, SelectStyle, Slider,
-    SliderStyle, Slot, StatusBar, StatusBarStyle, Stepper, StepperStyle, Switch, SwitchStyle,
-    Table, TableColumn, TableStyle, Tabs, TabsStyle, Text, WrapMode, Zone,
+    apply_input_edit, Alignment, Block, BorderType, Borders, Breakpoint, Checkbox, CheckboxStyle,
+    Constraint, CrossAlign, Direction, FormField, FormFieldStyle, GridCell, Input, InputEdit,
+    InputStyle, Justify, LayoutNode, List, ListStyle, Measure, MultiSelect, MultiSelectStyle,
+    Padding, Panel, PanelStyle, Paragraph, ProgressBar, ProgressBarStyle, RadioGroup,
+    RadioGroupStyle, ResolvedLayout, Select, SelectStyle, Slider, SliderStyle, Slot, StatusBar,
+    StatusBarStyle, Stepper, StepperStyle, Switch, SwitchStyle, Table, TableColumn, TableStyle,
+    Tabs, TabsStyle, Text, WrapMode, Zone,
 };
@@ -1,17 +1,105 @@
 use std::{
     collections::VecDeque,
-    io::{self, stdout},
+    env,
+    fs::File,
+    io::{self, stdout, BufWriter, Stdout, Write},
+    path::PathBuf,
     time::Duration,
 };
 
 use crossterm::{
-    cursor::{Hide, Show},
-    event::{self, Event as CrosstermEvent, KeyEvent},
+    cursor::{Hide, SetCursorStyle, Show},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent, KeyEvent},
     execute,
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
-use crate::{backend::TerminalBackend, App, Command, Event, Frame};
+use crate::{
+    backend::{ColorDepth, TerminalBackend},
+    cast::CastRecorder,
+    session::{EventLog, EventRecorder},
+    App, Command, Event, Frame,
+};
+
+const RECORD_CAST_ENV: &str = "PULSE_RECORD_CAST";
+const RECORD_EVENTS_ENV: &str = "PULSE_RECORD_EVENTS";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuntimeOptions {
+    tick_rate: Duration,
+    color_depth: Option<ColorDepth>,
+    synchronized_output: Option<bool>,
+    mouse_capture: bool,
+    record_cast: Option<PathBuf>,
+    record_events: Option<PathBuf>,
+}
+
+impl RuntimeOptions {
+    pub fn new() -> Self {
+        Self {
+            tick_rate: Duration::from_millis(250),
+            color_depth: None,
+            synchronized_output: None,
+            mouse_capture: false,
+            record_cast: None,
+            record_events: None,
+        }
+    }
+
+    pub fn tick_rate(mut self, tick_rate: Duration) -> Self {
+        self.tick_rate = tick_rate;
+        self
+    }
+
+    pub fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = Some(color_depth);
+        self
+    }
+
+    pub fn synchronized_output(mut self, enabled: bool) -> Self {
+        self.synchronized_output = Some(enabled);
+        self
+    }
+
+    pub fn mouse_capture(mut self, enabled: bool) -> Self {
+        self.mouse_capture = enabled;
+        self
+    }
+
+    pub fn record_cast(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_cast = Some(path.into());
+        self
+    }
+
+    pub fn record_events(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_events = Some(path.into());
+        self
+    }
+
+    fn cast_path(&self) -> Option<PathBuf> {
+        self.record_cast
+            .clone()
+            .or_else(|| path_from_env(RECORD_CAST_ENV))
+    }
+
+    fn events_path(&self) -> Option<PathBuf> {
+        self.record_events
+            .clone()
+            .or_else(|| path_from_env(RECORD_EVENTS_ENV))
+    }
+}
+
+impl Default for RuntimeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn path_from_env(key: &str) -> Option<PathBuf> {
+    env::var_os(key)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
 
 pub fn run<A, F>(app: &mut A, mut map_key: F) -> io::Result<()>
 where
@@ -20,60 +108,147 @@ where
 {
     run_with_events(app, Duration::from_millis(250), move |event| match event {
         Event::Key(key) => map_key(key),
-        Event::Resize { .. } | Event::Tick => None,
+        Event::Mouse(_) | Event::Resize { .. } | Event::Tick => None,
     })
 }
 
-pub fn run_with_events<A, F>(app: &mut A, tick_rate: Duration, mut map_event: F) -> io::Result<()>
+pub fn run_with_events<A, F>(app: &mut A, tick_rate: Duration, map_event: F) -> io::Result<()>
 where
     A: App,
     F: FnMut(Event) -> Option<A::Msg>,
 {
-    let _terminal_guard = TerminalGuard::enter()?;
+    run_with_options(app, RuntimeOptions::new().tick_rate(tick_rate), map_event)
+}
 
+pub fn run_with_options<A, F>(
+    app: &mut A,
+    options: RuntimeOptions,
+    mut map_event: F,
+) -> io::Result<()>
+where
+    A: App,
+    F: FnMut(Event) -> Option<A::Msg>,
+{
+    let tick_rate = options.tick_rate;
     let (width, height) = terminal::size()?;
+    let mut event_recording = options.events_path().map(|path| EventRecording {
+        recorder: EventRecorder::new(width, height),
+        path,
+        saved: false,
+    });
+    let _terminal_guard = TerminalGuard::enter(options.mouse_capture)?;
+
     let mut frame = Frame::new(width, height);
-    let mut backend = TerminalBackend::new(width, height);
+    let output = match options.cast_path() {
+        Some(path) => {
+            let cast = BufWriter::new(File::create(path)?);
+            RuntimeOutput::Recording(CastRecorder::new(stdout(), cast, width, height)?)
+        }
+        None => RuntimeOutput::Terminal(stdout()),
+    };
+    let mut backend = TerminalBackend::with_writer(output, width, height);
+    if let Some(color_depth) = options.color_depth {
+        backend = backend.with_color_depth(color_depth);
+    }
+    if let Some(enabled) = options.synchronized_output {
+        backend = backend.with_synchronized_output(enabled);
+    }
 
     app.init();
     draw(app, &mut frame, &mut backend)?;
 
     loop {
-        if !event::poll(tick_rate)? {
-            if process_event(app, Event::Tick, &mut map_event) {
-                break;
+        let event = if event::poll(tick_rate)? {
+            match event::read()? {
+                CrosstermEvent::Key(key) => Event::Key(key),
+                CrosstermEvent::Mouse(mouse) => Event::Mouse(mouse),
+                CrosstermEvent::Resize(width, height) => {
+                    if let RuntimeOutput::Recording(recorder) = backend.writer_mut() {
+                        recorder.resize(width, height)?;
+                    }
+                    Event::Resize { width, height }
+                }
+                _ => continue,
             }
-            continue;
+        } else {
+            Event::Tick
+        };
+
+        if let Some(recording) = event_recording.as_mut() {
+            recording.recorder.record(event);
         }
 
-        match event::read()? {
-            CrosstermEvent::Key(key) => {
-                if process_event(app, Event::Key(key), &mut map_event) {
-                    break;
-                }
+        match step(app, &mut frame, event, &mut map_event) {
+            Step::Quit => break,
+            Step::Redraw => draw(app, &mut frame, &mut backend)?,
+            Step::Idle => {}
+        }
+    }
 
-                draw(app, &mut frame, &mut backend)?;
-            }
-            CrosstermEvent::Resize(width, height) => {
-                frame = Frame::new(width, height);
+    if let Some(recording) = event_recording.as_mut() {
+        recording.save()?;
+    }
+    Ok(())
+}
 
-                if process_event(app, Event::Resize { width, height }, &mut map_event) {
-                    break;
-                }
+pub fn replay_events<A, F>(app: &mut A, log: &EventLog, mut map_event: F) -> Frame
+where
+    A: App,
+    F: FnMut(Event) -> Option<A::Msg>,
+{
+    let mut frame = Frame::new(log.width, log.height);
 
-                draw(app, &mut frame, &mut backend)?;
-            }
-            _ => {}
+    app.init();
+    render_view(app, &mut frame);
+
+    for recorded in &log.events {
+        match step(app, &mut frame, recorded.event, &mut map_event) {
+            Step::Quit => break,
+            Step::Redraw => render_view(app, &mut frame),
+            Step::Idle => {}
         }
     }
 
-    Ok(())
+    frame
 }
 
-fn draw<A: App>(app: &A, frame: &mut Frame, backend: &mut TerminalBackend) -> io::Result<()> {
+enum Step {
+    Quit,
+    Redraw,
+    Idle,
+}
+
+fn step<A, F>(app: &mut A, frame: &mut Frame, event: Event, map_event: &mut F) -> Step
+where
+    A: App,
+    F: FnMut(Event) -> Option<A::Msg>,
+{
+    if let Event::Resize { width, height } = event {
+        *frame = Frame::new(width, height);
+    }
+
+    if process_event(app, event, map_event) {
+        return Step::Quit;
+    }
+
+    match event {
+        Event::Tick => Step::Idle,
+        Event::Key(_) | Event::Mouse(_) | Event::Resize { .. } => Step::Redraw,
+    }
+}
+
+fn draw<A: App>(
+    app: &A,
+    frame: &mut Frame,
+    backend: &mut TerminalBackend<RuntimeOutput>,
+) -> io::Result<()> {
+    render_view(app, frame);
+    backend.render(frame)
+}
+
+fn render_view<A: App>(app: &A, frame: &mut Frame) {
     frame.clear();
     app.view(frame);
-    backend.render(frame)
 }
 
 fn process_message<A: App>(app: &mut A, msg: A::Msg) -> bool {
@@ -118,28 +293,88 @@ where
     }
 }
 
-struct TerminalGuard;
+enum RuntimeOutput {
+    Terminal(Stdout),
+    Recording(CastRecorder<Stdout, BufWriter<File>>),
+}
+
+impl Write for RuntimeOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            RuntimeOutput::Terminal(out) => out.write(buf),
+            RuntimeOutput::Recording(recorder) => recorder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            RuntimeOutput::Terminal(out) => out.flush(),
+            RuntimeOutput::Recording(recorder) => recorder.flush(),
+        }
+    }
+}
+
+struct EventRecording {
+    recorder: EventRecorder,
+    path: PathBuf,
+    saved: bool,
+}
+
+impl EventRecording {
+    fn save(&mut self) -> io::Result<()> {
+        self.saved = true;
+        self.recorder
+            .log()
+            .save(&self.path)
+            .map_err(io::Error::other)
+    }
+}
+
+impl Drop for EventRecording {
+    fn drop(&mut self) {
+        if !self.saved {
+            let _ = self.save();
+        }
+    }
+}
+
+struct TerminalGuard {
+    mouse_capture: bool,
+}
 
 impl TerminalGuard {
-    fn enter() -> io::Result<Self> {
+    fn enter(mouse_capture: bool) -> io::Result<Self> {
         terminal::enable_raw_mode()?;
         execute!(stdout(), EnterAlternateScreen, Hide)?;
-        Ok(Self)
+        if mouse_capture {
+            execute!(stdout(), EnableMouseCapture)?;
+        }
+        Ok(Self { mouse_capture })
     }
 }
 
 impl Drop for TerminalGuard {
     fn drop(&mut self) {
-        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        if self.mouse_capture {
+            let _ = execute!(stdout(), DisableMouseCapture);
+        }
+        let _ = execute!(
+            stdout(),
+            SetCursorStyle::DefaultUserShape,
+            Show,
+            LeaveAlternateScreen
+        );
         let _ = terminal::disable_raw_mode();
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{App, Command, Event, Frame};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-    use super::{process_event, process_message};
+    use crate::{App, Command, Event, EventLog, Frame};
+
+    use super::{process_event, process_message, replay_events};
 
     #[derive(Debug, Clone, Copy, Eq, PartialEq)]
     enum Msg {
@@ -253,4 +488,84 @@ mod tests {
             ]
         );
     }
+
+    struct Counter {
+        value: i32,
+        ticks: u32,
+    }
+
+    enum CounterMsg {
+        Increment,
+        Tick,
+        Quit,
+    }
+
+    impl App for Counter {
+        type Msg = CounterMsg;
+
+        fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg> {
+            match msg {
+                CounterMsg::Increment => self.value += 1,
+                CounterMsg::Tick => self.ticks += 1,
+                CounterMsg::Quit => return Command::quit(),
+            }
+            Command::none()
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            frame.print(0, 0, &format!("{}/{}", self.value, self.ticks));
+        }
+    }
+
+    fn map_counter_event(event: Event) -> Option<CounterMsg> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char('+') => Some(CounterMsg::Increment),
+                KeyCode::Char('q') => Some(CounterMsg::Quit),
+                _ => None,
+            },
+            Event::Tick => Some(CounterMsg::Tick),
+            Event::Mouse(_) | Event::Resize { .. } => None,
+        }
+    }
+
+    fn key(ch: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn replay_events_drives_app_from_log() {
+        let mut log = EventLog::new(10, 2);
+        log.push(0, key('+'));
+        log.push(250, Event::Tick);
+        log.push(300, key('+'));
+        log.push(
+            400,
+            Event::Resize {
+                width: 12,
+                height: 3,
+            },
+        );
+
+        let mut app = Counter { value: 0, ticks: 0 };
+        let frame = replay_events(&mut app, &log, map_counter_event);
+
+        assert_eq!((app.value, app.ticks), (2, 1));
+        assert_eq!((frame.width(), frame.height()), (12, 3));
+        assert_eq!(frame.char_at(0, 0), Some('2'));
+        assert_eq!(frame.char_at(2, 0), Some('1'));
+    }
+
+    #[test]
+    fn replay_events_stops_at_quit() {
+        let mut log = EventLog::new(10, 2);
+        log.push(0, key('+'));
+        log.push(10, key('q'));
+        log.push(20, key('+'));
+
+        let mut app = Counter { value: 0, ticks: 0 };
+        replay_events(&mut app, &log, map_counter_event);
+
+        assert_eq!(app.value, 1);
+    }
 }
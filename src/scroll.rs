@@ -0,0 +1,529 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind};
+
+use crate::{Color, Frame, Rect, Style, Theme};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScrollViewMsg {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    ScrollTo { x: u16, y: u16 },
+    ScrollIntoView(Rect),
+    Mouse(MouseEvent),
+}
+
+impl ScrollViewMsg {
+    pub fn from_key(key: KeyEvent) -> Option<Self> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Up => Some(Self::Up),
+            KeyCode::Down => Some(Self::Down),
+            KeyCode::Left => Some(Self::Left),
+            KeyCode::Right => Some(Self::Right),
+            KeyCode::PageUp => Some(Self::PageUp),
+            KeyCode::PageDown => Some(Self::PageDown),
+            KeyCode::Home => Some(Self::Top),
+            KeyCode::End => Some(Self::Bottom),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScrollbarStyle {
+    pub track: Style,
+    pub thumb: Style,
+}
+
+impl ScrollbarStyle {
+    pub fn from_theme(theme: &Theme) -> Self {
+        let defaults = Self::default();
+        Self {
+            track: theme.style_or("scrollbar.track", defaults.track),
+            thumb: theme.style_or("scrollbar.thumb", defaults.thumb),
+        }
+    }
+}
+
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        Self {
+            track: Style::new().fg(Color::Ansi(240)),
+            thumb: Style::new().fg(Color::Ansi(39)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ScrollView {
+    content_width: u16,
+    content_height: u16,
+    offset_x: u16,
+    offset_y: u16,
+    vertical_scrollbar: bool,
+    horizontal_scrollbar: bool,
+    wheel_step: u16,
+    styles: ScrollbarStyle,
+    area: Rect,
+    viewport: Rect,
+}
+
+impl ScrollView {
+    pub fn new(content_width: u16, content_height: u16) -> Self {
+        Self {
+            content_width,
+            content_height,
+            offset_x: 0,
+            offset_y: 0,
+            vertical_scrollbar: true,
+            horizontal_scrollbar: true,
+            wheel_step: 3,
+            styles: ScrollbarStyle::default(),
+            area: Rect::new(0, 0, 0, 0),
+            viewport: Rect::new(0, 0, 0, 0),
+        }
+    }
+
+    pub fn vertical_scrollbar(mut self, enabled: bool) -> Self {
+        self.vertical_scrollbar = enabled;
+        self
+    }
+
+    pub fn horizontal_scrollbar(mut self, enabled: bool) -> Self {
+        self.horizontal_scrollbar = enabled;
+        self
+    }
+
+    pub fn wheel_step(mut self, step: u16) -> Self {
+        self.wheel_step = step.max(1);
+        self
+    }
+
+    pub fn styles(mut self, styles: ScrollbarStyle) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    pub fn content_size(&self) -> (u16, u16) {
+        (self.content_width, self.content_height)
+    }
+
+    pub fn set_content_size(&mut self, width: u16, height: u16) {
+        self.content_width = width;
+        self.content_height = height;
+        self.set_offset(self.offset_x, self.offset_y);
+    }
+
+    pub fn offset(&self) -> (u16, u16) {
+        (self.offset_x, self.offset_y)
+    }
+
+    pub fn set_area(&mut self, area: Rect) {
+        self.area = area;
+        self.viewport = self.split(area).0;
+        self.set_offset(self.offset_x, self.offset_y);
+    }
+
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    pub fn set_offset(&mut self, x: u16, y: u16) {
+        let (max_x, max_y) = self.max_offset(self.viewport);
+        self.offset_x = x.min(max_x);
+        self.offset_y = y.min(max_y);
+    }
+
+    pub fn viewport(&self) -> Rect {
+        self.viewport
+    }
+
+    pub fn update(&mut self, msg: ScrollViewMsg) {
+        let viewport = self.viewport;
+        let (x, y) = (self.offset_x, self.offset_y);
+        let page = viewport.height.saturating_sub(1).max(1);
+
+        match msg {
+            ScrollViewMsg::Up => self.set_offset(x, y.saturating_sub(1)),
+            ScrollViewMsg::Down => self.set_offset(x, y.saturating_add(1)),
+            ScrollViewMsg::Left => self.set_offset(x.saturating_sub(1), y),
+            ScrollViewMsg::Right => self.set_offset(x.saturating_add(1), y),
+            ScrollViewMsg::PageUp => self.set_offset(x, y.saturating_sub(page)),
+            ScrollViewMsg::PageDown => self.set_offset(x, y.saturating_add(page)),
+            ScrollViewMsg::Top => self.set_offset(0, 0),
+            ScrollViewMsg::Bottom => self.set_offset(x, u16::MAX),
+            ScrollViewMsg::ScrollTo { x, y } => self.set_offset(x, y),
+            ScrollViewMsg::ScrollIntoView(target) => self.scroll_into_view(target),
+            ScrollViewMsg::Mouse(mouse) => self.handle_mouse(mouse),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, content: impl FnOnce(&mut Frame, Rect)) {
+        let (viewport, vertical, horizontal) = self.split(area);
+        let (max_x, max_y) = self.max_offset(viewport);
+        let (offset_x, offset_y) = (self.offset_x.min(max_x), self.offset_y.min(max_y));
+        let visible = Rect::new(offset_x, offset_y, viewport.width, viewport.height);
+
+        frame.render_in(viewport, |frame| content(frame, visible));
+
+        if let Some(track) = vertical {
+            Scrollbar::vertical(
+                self.content_height as usize,
+                viewport.height as usize,
+                offset_y as usize,
+            )
+            .styles(self.styles)
+            .render(frame, track);
+        }
+        if let Some(track) = horizontal {
+            Scrollbar::horizontal(
+                self.content_width as usize,
+                viewport.width as usize,
+                offset_x as usize,
+            )
+            .styles(self.styles)
+            .render(frame, track);
+        }
+    }
+
+    fn split(&self, area: Rect) -> (Rect, Option<Rect>, Option<Rect>) {
+        let mut vertical = self.vertical_scrollbar && self.content_height > area.height;
+        let horizontal = self.horizontal_scrollbar
+            && self.content_width > area.width.saturating_sub(u16::from(vertical));
+        if !vertical {
+            vertical = self.vertical_scrollbar
+                && self.content_height > area.height.saturating_sub(u16::from(horizontal));
+        }
+
+        let width = area.width.saturating_sub(u16::from(vertical));
+        let height = area.height.saturating_sub(u16::from(horizontal));
+        (
+            Rect::new(area.x, area.y, width, height),
+            vertical.then(|| Rect::new(area.x.saturating_add(width), area.y, 1, height)),
+            horizontal.then(|| Rect::new(area.x, area.y.saturating_add(height), width, 1)),
+        )
+    }
+
+    fn max_offset(&self, viewport: Rect) -> (u16, u16) {
+        (
+            self.content_width.saturating_sub(viewport.width),
+            self.content_height.saturating_sub(viewport.height),
+        )
+    }
+
+    fn scroll_into_view(&mut self, target: Rect) {
+        let viewport = self.viewport;
+        let fit = |offset: u16, start: u16, len: u16, size: u16| {
+            let end = start.saturating_add(len);
+            if start < offset || len >= size {
+                start
+            } else if end > offset.saturating_add(size) {
+                end - size
+            } else {
+                offset
+            }
+        };
+
+        self.set_offset(
+            fit(self.offset_x, target.x, target.width, viewport.width),
+            fit(self.offset_y, target.y, target.height, viewport.height),
+        );
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if !self.area.contains(mouse.column, mouse.row) {
+            return;
+        }
+
+        let (x, y) = (self.offset_x, self.offset_y);
+        let step = self.wheel_step;
+        let sideways = mouse.modifiers.contains(KeyModifiers::SHIFT);
+        match mouse.kind {
+            MouseEventKind::ScrollUp if sideways => self.set_offset(x.saturating_sub(step), y),
+            MouseEventKind::ScrollDown if sideways => self.set_offset(x.saturating_add(step), y),
+            MouseEventKind::ScrollUp => self.set_offset(x, y.saturating_sub(step)),
+            MouseEventKind::ScrollDown => self.set_offset(x, y.saturating_add(step)),
+            MouseEventKind::ScrollLeft => self.set_offset(x.saturating_sub(step), y),
+            MouseEventKind::ScrollRight => self.set_offset(x.saturating_add(step), y),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ScrollbarOrientation {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Scrollbar {
+    orientation: ScrollbarOrientation,
+    content_length: usize,
+    viewport_length: usize,
+    offset: usize,
+    track_symbol: Option<char>,
+    thumb_symbol: char,
+    styles: ScrollbarStyle,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Self {
+        Self {
+            orientation: ScrollbarOrientation::Vertical,
+            content_length: 0,
+            viewport_length: 0,
+            offset: 0,
+            track_symbol: None,
+            thumb_symbol: '█',
+            styles: ScrollbarStyle::default(),
+        }
+    }
+}
+
+impl Scrollbar {
+    pub fn vertical(content_length: usize, viewport_length: usize, offset: usize) -> Self {
+        Self::default().position(content_length, viewport_length, offset)
+    }
+
+    pub fn horizontal(content_length: usize, viewport_length: usize, offset: usize) -> Self {
+        Self::vertical(content_length, viewport_length, offset)
+            .orientation(ScrollbarOrientation::Horizontal)
+    }
+
+    pub fn orientation(mut self, orientation: ScrollbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn position(
+        mut self,
+        content_length: usize,
+        viewport_length: usize,
+        offset: usize,
+    ) -> Self {
+        self.content_length = content_length;
+        self.viewport_length = viewport_length;
+        self.offset = offset;
+        self
+    }
+
+    pub fn track_symbol(mut self, symbol: char) -> Self {
+        self.track_symbol = Some(symbol);
+        self
+    }
+
+    pub fn thumb_symbol(mut self, symbol: char) -> Self {
+        self.thumb_symbol = symbol;
+        self
+    }
+
+    pub fn styles(mut self, styles: ScrollbarStyle) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    pub fn is_scrollable(&self) -> bool {
+        self.content_length > self.viewport_length
+    }
+
+    pub fn thumb(&self, track_length: u16) -> (u16, u16) {
+        let len = track_length as u128;
+        if len == 0 || self.content_length == 0 {
+            return (0, 0);
+        }
+
+        let content = self.content_length as u128;
+        let viewport = self.viewport_length as u128;
+        let thumb_len = (len * viewport / content).clamp(1, len);
+        let scrollable = content.saturating_sub(viewport);
+        let thumb_start = ((len - thumb_len) * (self.offset as u128).min(scrollable))
+            .checked_div(scrollable)
+            .unwrap_or(0);
+        (thumb_start as u16, thumb_len as u16)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let (len, track_symbol) = match self.orientation {
+            ScrollbarOrientation::Vertical => (area.height, self.track_symbol.unwrap_or('│')),
+            ScrollbarOrientation::Horizontal => (area.width, self.track_symbol.unwrap_or('─')),
+        };
+        if area.width == 0 || area.height == 0 || self.content_length == 0 {
+            return;
+        }
+
+        let (thumb_start, thumb_len) = self.thumb(len);
+        for idx in 0..len {
+            let (ch, style) = if idx >= thumb_start && idx < thumb_start + thumb_len {
+                (self.thumb_symbol, self.styles.thumb)
+            } else {
+                (track_symbol, self.styles.track)
+            };
+            let (x, y) = match self.orientation {
+                ScrollbarOrientation::Vertical => (area.x, area.y.saturating_add(idx)),
+                ScrollbarOrientation::Horizontal => (area.x.saturating_add(idx), area.y),
+            };
+            frame.set_cell(x, y, ch, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+
+    use crate::{Frame, Rect};
+
+    use super::{ScrollView, ScrollViewMsg, Scrollbar};
+
+    fn numbered_lines(frame: &mut Frame, visible: Rect) {
+        for row in 0..visible.height {
+            let line = format!("line{:02}-abcdefghij", visible.y + row);
+            let window: String = line.chars().skip(visible.x as usize).collect();
+            frame.print(0, row, &window);
+        }
+    }
+
+    #[test]
+    fn renders_offset_window_with_scrollbars() {
+        let mut view = ScrollView::new(16, 20);
+        let mut frame = Frame::new(8, 5);
+        view.set_area(Rect::new(0, 0, 8, 5));
+
+        view.update(ScrollViewMsg::ScrollTo { x: 2, y: 10 });
+        view.render(&mut frame, Rect::new(0, 0, 8, 5), numbered_lines);
+
+        assert_eq!(view.viewport(), Rect::new(0, 0, 7, 4));
+        assert_eq!(view.offset(), (2, 10));
+        assert_eq!(frame.row_text(0), "ne10-ab│");
+        assert_eq!(frame.row_text(1), "ne11-ab█");
+        assert_eq!(frame.row_text(3), "ne13-ab│");
+        assert_eq!(frame.row_text(4), "███──── ");
+    }
+
+    #[test]
+    fn offsets_clamp_to_content_and_keys_page() {
+        let mut view = ScrollView::new(10, 30).horizontal_scrollbar(false);
+        view.set_area(Rect::new(0, 0, 10, 10));
+
+        view.update(ScrollViewMsg::PageDown);
+        assert_eq!(view.offset(), (0, 9));
+        view.update(ScrollViewMsg::Bottom);
+        assert_eq!(view.offset(), (0, 20));
+        view.update(ScrollViewMsg::Down);
+        assert_eq!(view.offset(), (0, 20));
+        view.update(ScrollViewMsg::Right);
+        assert_eq!(view.offset(), (1, 20));
+        view.update(ScrollViewMsg::Top);
+        assert_eq!(view.offset(), (0, 0));
+
+        let key = KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE);
+        assert_eq!(ScrollViewMsg::from_key(key), Some(ScrollViewMsg::PageDown));
+    }
+
+    #[test]
+    fn scroll_into_view_moves_the_minimum_distance() {
+        let mut view = ScrollView::new(40, 40);
+        view.set_area(Rect::new(0, 0, 11, 11));
+
+        view.update(ScrollViewMsg::ScrollIntoView(Rect::new(5, 14, 3, 2)));
+        assert_eq!(view.offset(), (0, 6));
+
+        view.update(ScrollViewMsg::ScrollIntoView(Rect::new(12, 3, 4, 1)));
+        assert_eq!(view.offset(), (6, 3));
+
+        view.update(ScrollViewMsg::ScrollIntoView(Rect::new(8, 5, 2, 2)));
+        assert_eq!(view.offset(), (6, 3));
+    }
+
+    #[test]
+    fn wheel_scrolls_only_inside_area() {
+        let mut view = ScrollView::new(30, 30).wheel_step(2);
+        view.set_area(Rect::new(0, 0, 10, 10));
+        let wheel = |kind, column, modifiers| {
+            ScrollViewMsg::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 1,
+                modifiers,
+            })
+        };
+
+        view.update(wheel(MouseEventKind::ScrollDown, 3, KeyModifiers::NONE));
+        view.update(wheel(MouseEventKind::ScrollDown, 3, KeyModifiers::SHIFT));
+        view.update(wheel(MouseEventKind::ScrollDown, 15, KeyModifiers::NONE));
+
+        assert_eq!(view.offset(), (2, 2));
+    }
+
+    #[test]
+    fn content_draws_only_the_visible_window() {
+        let mut view = ScrollView::new(10, 10_000).vertical_scrollbar(false);
+        let mut frame = Frame::new(6, 4);
+        let area = Rect::new(1, 0, 5, 4);
+        view.set_area(area);
+        view.update(ScrollViewMsg::ScrollTo { x: 1, y: 5_000 });
+
+        let mut drawn = None;
+        view.render(&mut frame, area, |frame, visible| {
+            drawn = Some(visible);
+            frame.set_cursor(2, 1);
+        });
+
+        assert_eq!(drawn, Some(Rect::new(1, 5_000, 5, 3)));
+        let cursor = frame.cursor().expect("cursor is visible");
+        assert_eq!((cursor.x, cursor.y), (3, 1));
+    }
+
+    #[test]
+    fn offsets_clamp_before_an_area_is_set() {
+        let mut view = ScrollView::new(10, 10);
+        view.update(ScrollViewMsg::ScrollTo { x: 40, y: 40 });
+        assert_eq!(view.offset(), (10, 10));
+
+        view.set_area(Rect::new(0, 0, 6, 6));
+        assert_eq!(view.offset(), (5, 5));
+    }
+
+    #[test]
+    fn scrollbar_thumb_is_proportional_and_clamped() {
+        assert_eq!(Scrollbar::vertical(100, 25, 0).thumb(8), (0, 2));
+        assert_eq!(Scrollbar::vertical(100, 25, 75).thumb(8), (6, 2));
+        assert_eq!(Scrollbar::vertical(100, 25, 500).thumb(8), (6, 2));
+        assert_eq!(Scrollbar::vertical(1000, 10, 0).thumb(8), (0, 1));
+        assert_eq!(Scrollbar::vertical(5, 10, 0).thumb(8), (0, 8));
+        assert!(!Scrollbar::vertical(5, 10, 0).is_scrollable());
+        assert_eq!(
+            Scrollbar::vertical(usize::MAX, usize::MAX / 2, usize::MAX).thumb(8),
+            (5, 3)
+        );
+    }
+
+    #[test]
+    fn scrollbar_at_max_coordinates_does_not_overflow() {
+        let mut frame = Frame::new(2, 2);
+        Scrollbar::vertical(10, 2, 3).render(&mut frame, Rect::new(u16::MAX, u16::MAX, 1, 4));
+        Scrollbar::horizontal(10, 2, 3).render(&mut frame, Rect::new(u16::MAX, 0, 4, 1));
+
+        assert_eq!(frame.char_at(0, 0), Some(' '));
+    }
+
+    #[test]
+    fn horizontal_scrollbar_renders_glyphs() {
+        let mut frame = Frame::new(6, 1);
+        Scrollbar::horizontal(12, 6, 6)
+            .thumb_symbol('=')
+            .render(&mut frame, Rect::new(0, 0, 6, 1));
+
+        assert_eq!(frame.row_text(0), "───===");
+    }
+}
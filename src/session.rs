@@ -0,0 +1,160 @@
+use std::{fmt, fs, path::Path, time::Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Event;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventLog {
+    pub width: u16,
+    pub height: u16,
+    #[serde(default)]
+    pub events: Vec<RecordedEvent>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecordedEvent {
+    pub at_ms: u64,
+    pub event: Event,
+}
+
+impl EventLog {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, at_ms: u64, event: Event) {
+        self.events.push(RecordedEvent { at_ms, event });
+    }
+
+    pub fn from_json_str(input: &str) -> Result<Self, EventLogError> {
+        serde_json::from_str(input)
+            .map_err(|err| EventLogError::Parse(format!("invalid event log JSON: {err}")))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, EventLogError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|err| {
+            EventLogError::Io(format!("failed to read {}: {err}", path.display()))
+        })?;
+        Self::from_json_str(&data)
+    }
+
+    pub fn to_json_string(&self) -> Result<String, EventLogError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|err| EventLogError::Parse(format!("failed to encode event log: {err}")))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), EventLogError> {
+        let path = path.as_ref();
+        let data = self.to_json_string()?;
+        fs::write(path, data)
+            .map_err(|err| EventLogError::Io(format!("failed to write {}: {err}", path.display())))
+    }
+}
+
+pub struct EventRecorder {
+    log: EventLog,
+    started: Instant,
+}
+
+impl EventRecorder {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            log: EventLog::new(width, height),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn record(&mut self, event: Event) {
+        let at_ms = self.started.elapsed().as_millis().min(u64::MAX as u128) as u64;
+        self.log.push(at_ms, event);
+    }
+
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
+
+    pub fn finish(self) -> EventLog {
+        self.log
+    }
+}
+
+#[derive(Debug)]
+pub enum EventLogError {
+    Io(String),
+    Parse(String),
+}
+
+impl fmt::Display for EventLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventLogError::Io(message) => write!(f, "{message}"),
+            EventLogError::Parse(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for EventLogError {}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::Event;
+
+    use super::{EventLog, EventRecorder};
+
+    #[test]
+    fn event_log_round_trips_through_json() {
+        let mut log = EventLog::new(80, 24);
+        log.push(
+            0,
+            Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+        );
+        log.push(
+            120,
+            Event::Resize {
+                width: 100,
+                height: 30,
+            },
+        );
+        log.push(370, Event::Tick);
+
+        let json = log.to_json_string().expect("log encodes");
+        let decoded = EventLog::from_json_str(&json).expect("log decodes");
+
+        assert_eq!(decoded, log);
+    }
+
+    #[test]
+    fn event_log_rejects_unknown_fields() {
+        let err = EventLog::from_json_str(r#"{"width": 10, "height": 5, "speed": 2}"#)
+            .expect_err("unknown field should fail");
+
+        assert!(err.to_string().contains("speed"));
+    }
+
+    #[test]
+    fn recorder_keeps_events_in_order_with_monotonic_time() {
+        let mut recorder = EventRecorder::new(20, 10);
+        recorder.record(Event::Tick);
+        recorder.record(Event::Resize {
+            width: 30,
+            height: 12,
+        });
+
+        let log = recorder.finish();
+
+        assert_eq!((log.width, log.height), (20, 10));
+        assert_eq!(log.events.len(), 2);
+        assert_eq!(log.events[0].event, Event::Tick);
+        assert!(log.events[0].at_ms <= log.events[1].at_ms);
+    }
+}
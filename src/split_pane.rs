@@ -0,0 +1,291 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+use crate::{
+    BorderType, Color, Command, Component, Constraint, Direction, Frame, LayoutNode, Rect, Slot,
+    Style, Theme,
+};
+
+const RATIO_SCALE: u32 = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplitPaneMsg {
+    Grow,
+    Shrink,
+    SetRatio(f32),
+    Mouse(MouseEvent, Rect),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SplitPaneStyle {
+    pub divider: Style,
+    pub active: Style,
+}
+
+impl SplitPaneStyle {
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            divider: theme.style_or("split.divider", Style::new().fg(Color::Ansi(39))),
+            active: theme.style_or("split.divider.active", Style::new().fg(Color::Ansi(214))),
+        }
+    }
+}
+
+impl Default for SplitPaneStyle {
+    fn default() -> Self {
+        Self {
+            divider: Style::new().fg(Color::Ansi(39)),
+            active: Style::new().fg(Color::Ansi(214)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SplitPane {
+    direction: Direction,
+    ratio: f32,
+    min_ratio: f32,
+    max_ratio: f32,
+    step: f32,
+    styles: SplitPaneStyle,
+    dragging: bool,
+}
+
+impl SplitPane {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            ratio: 0.5,
+            min_ratio: 0.1,
+            max_ratio: 0.9,
+            step: 0.05,
+            styles: SplitPaneStyle::default(),
+            dragging: false,
+        }
+    }
+
+    pub fn with_ratio(mut self, ratio: f32) -> Self {
+        self.set_ratio(ratio);
+        self
+    }
+
+    pub fn with_limits(mut self, min_ratio: f32, max_ratio: f32) -> Self {
+        self.min_ratio = min_ratio.clamp(0.0, 1.0);
+        self.max_ratio = max_ratio.clamp(self.min_ratio, 1.0);
+        self.set_ratio(self.ratio);
+        self
+    }
+
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    pub fn styles(mut self, styles: SplitPaneStyle) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub fn set_ratio(&mut self, ratio: f32) {
+        let ratio = if ratio.is_nan() { self.ratio } else { ratio };
+        self.ratio = ratio.clamp(self.min_ratio, self.max_ratio);
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    pub fn layout(&self) -> LayoutNode {
+        let ratio = (self.ratio * RATIO_SCALE as f32).round() as u32;
+        LayoutNode::split(
+            "split_pane",
+            self.direction,
+            [
+                Slot::new(
+                    Constraint::Ratio(ratio, RATIO_SCALE),
+                    LayoutNode::leaf("first"),
+                ),
+                Slot::new(Constraint::Fixed(1), LayoutNode::leaf("divider")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("second")),
+            ],
+        )
+    }
+
+    pub fn areas(&self, area: Rect) -> (Rect, Rect) {
+        let resolved = self.layout().resolve(area);
+        let empty = Rect::new(area.x, area.y, 0, 0);
+        (
+            resolved.area("first").unwrap_or(empty),
+            resolved.area("second").unwrap_or(empty),
+        )
+    }
+
+    pub fn divider_area(&self, area: Rect) -> Rect {
+        self.layout()
+            .resolve(area)
+            .area("divider")
+            .unwrap_or(Rect::new(area.x, area.y, 0, 0))
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent, area: Rect) {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.dragging = self.divider_area(area).contains(mouse.column, mouse.row);
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                let (position, start, total) = match self.direction {
+                    Direction::Horizontal => (mouse.column, area.x, area.width),
+                    Direction::Vertical => (mouse.row, area.y, area.height),
+                };
+                if total > 0 {
+                    let offset = position.saturating_sub(start).min(total);
+                    self.set_ratio((offset as f32 + 0.5) / total as f32);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.dragging = false,
+            _ => {}
+        }
+    }
+}
+
+impl Component for SplitPane {
+    type Msg = SplitPaneMsg;
+
+    fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg> {
+        match msg {
+            SplitPaneMsg::Grow => self.set_ratio(self.ratio + self.step),
+            SplitPaneMsg::Shrink => self.set_ratio(self.ratio - self.step),
+            SplitPaneMsg::SetRatio(ratio) => self.set_ratio(ratio),
+            SplitPaneMsg::Mouse(mouse, area) => self.handle_mouse(mouse, area),
+        }
+
+        Command::none()
+    }
+
+    fn view(&self, frame: &mut Frame, area: Rect) {
+        let divider = self.divider_area(area);
+        let style = if self.dragging {
+            self.styles.active
+        } else {
+            self.styles.divider
+        };
+        match self.direction {
+            Direction::Horizontal => frame.vertical_line(
+                divider.x,
+                divider.y,
+                divider.height,
+                BorderType::Unicode,
+                style,
+            ),
+            Direction::Vertical => frame.horizontal_line(
+                divider.x,
+                divider.y,
+                divider.width,
+                BorderType::Unicode,
+                style,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    use crate::{Component, Direction, Frame, Rect};
+
+    use super::{SplitPane, SplitPaneMsg};
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16, area: Rect) -> SplitPaneMsg {
+        SplitPaneMsg::Mouse(
+            MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            },
+            area,
+        )
+    }
+
+    #[test]
+    fn splits_area_around_divider() {
+        let pane = SplitPane::new(Direction::Horizontal).with_ratio(0.3);
+        let area = Rect::new(0, 0, 40, 10);
+
+        let (left, right) = pane.areas(area);
+
+        assert_eq!(left, Rect::new(0, 0, 12, 10));
+        assert_eq!(pane.divider_area(area), Rect::new(12, 0, 1, 10));
+        assert_eq!(right, Rect::new(13, 0, 27, 10));
+    }
+
+    #[test]
+    fn keyboard_resizes_within_limits() {
+        let mut pane = SplitPane::new(Direction::Vertical)
+            .with_limits(0.2, 0.6)
+            .with_step(0.25);
+
+        pane.update(SplitPaneMsg::Grow);
+        assert_eq!(pane.ratio(), 0.6);
+
+        pane.update(SplitPaneMsg::Shrink);
+        pane.update(SplitPaneMsg::Shrink);
+        assert_eq!(pane.ratio(), 0.2);
+
+        pane.update(SplitPaneMsg::SetRatio(0.4));
+        assert_eq!(pane.ratio(), 0.4);
+    }
+
+    #[test]
+    fn mouse_drag_moves_divider() {
+        let mut pane = SplitPane::new(Direction::Horizontal);
+        let mut frame = Frame::new(20, 3);
+        let area = Rect::new(0, 0, 20, 3);
+        pane.view(&mut frame, area);
+
+        pane.update(mouse(MouseEventKind::Down(MouseButton::Left), 10, 1, area));
+        assert!(pane.is_dragging());
+        pane.update(mouse(MouseEventKind::Drag(MouseButton::Left), 6, 1, area));
+        pane.update(mouse(MouseEventKind::Up(MouseButton::Left), 6, 1, area));
+
+        assert!(!pane.is_dragging());
+        assert_eq!(pane.divider_area(area), Rect::new(6, 0, 1, 3));
+
+        frame.clear();
+        pane.view(&mut frame, area);
+        assert_eq!(frame.row_text(0), "      │             ");
+    }
+
+    #[test]
+    fn drag_outside_divider_is_ignored() {
+        let mut pane = SplitPane::new(Direction::Vertical);
+        let mut frame = Frame::new(10, 10);
+        let area = Rect::new(0, 0, 10, 10);
+        pane.view(&mut frame, area);
+
+        pane.update(mouse(MouseEventKind::Down(MouseButton::Left), 3, 2, area));
+        pane.update(mouse(MouseEventKind::Drag(MouseButton::Left), 3, 8, area));
+
+        assert!(!pane.is_dragging());
+        assert_eq!(pane.ratio(), 0.5);
+        assert_eq!(frame.row_text(5), "──────────");
+    }
+
+    #[test]
+    fn mouse_hit_tests_the_area_in_the_message() {
+        let mut pane = SplitPane::new(Direction::Horizontal);
+        let area = Rect::new(20, 0, 20, 3);
+
+        pane.update(mouse(MouseEventKind::Down(MouseButton::Left), 10, 1, area));
+        assert!(!pane.is_dragging());
+
+        pane.update(mouse(MouseEventKind::Down(MouseButton::Left), 30, 1, area));
+        assert!(pane.is_dragging());
+        pane.update(mouse(MouseEventKind::Drag(MouseButton::Left), 25, 1, area));
+        assert_eq!(pane.divider_area(area), Rect::new(25, 0, 1, 3));
+    }
+}
@@ -1,10 +1,123 @@
+use std::{fmt, str::FromStr};
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Color {
     Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
     Ansi(u8),
     Rgb(u8, u8, u8),
 }
 
+const NAMED_COLORS: [(&str, Color); 16] = [
+    ("black", Color::Black),
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("blue", Color::Blue),
+    ("magenta", Color::Magenta),
+    ("cyan", Color::Cyan),
+    ("white", Color::White),
+    ("bright_black", Color::BrightBlack),
+    ("bright_red", Color::BrightRed),
+    ("bright_green", Color::BrightGreen),
+    ("bright_yellow", Color::BrightYellow),
+    ("bright_blue", Color::BrightBlue),
+    ("bright_magenta", Color::BrightMagenta),
+    ("bright_cyan", Color::BrightCyan),
+    ("bright_white", Color::BrightWhite),
+];
+
+impl Color {
+    pub(crate) fn named_index(self) -> Option<u8> {
+        NAMED_COLORS
+            .iter()
+            .position(|(_, color)| *color == self)
+            .map(|idx| idx as u8)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Default => write!(f, "default"),
+            Color::Ansi(value) => write!(f, "ansi:{value}"),
+            Color::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            named => {
+                let idx = named.named_index().unwrap_or(0) as usize;
+                write!(f, "{}", NAMED_COLORS[idx].0)
+            }
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let value = input.trim().to_ascii_lowercase();
+        let invalid = |reason: &str| ParseColorError(format!("invalid color `{input}`: {reason}"));
+
+        if value == "default" {
+            return Ok(Color::Default);
+        }
+
+        if let Some(hex) = value.strip_prefix('#') {
+            let digits: Vec<u8> = hex
+                .chars()
+                .map(|ch| ch.to_digit(16).map(|digit| digit as u8))
+                .collect::<Option<_>>()
+                .ok_or_else(|| invalid("expected hexadecimal digits"))?;
+            return match digits.as_slice() {
+                [r, g, b] => Ok(Color::Rgb(r * 17, g * 17, b * 17)),
+                [r1, r2, g1, g2, b1, b2] => {
+                    Ok(Color::Rgb(r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2))
+                }
+                _ => Err(invalid("expected #rgb or #rrggbb")),
+            };
+        }
+
+        if let Some(index) = value.strip_prefix("ansi:") {
+            return index
+                .parse::<u8>()
+                .map(Color::Ansi)
+                .map_err(|_| invalid("expected an ANSI index between 0 and 255"));
+        }
+
+        let name = value.replace('-', "_");
+        NAMED_COLORS
+            .iter()
+            .find(|(candidate, _)| *candidate == name)
+            .map(|(_, color)| *color)
+            .ok_or_else(|| invalid("unknown color name"))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Modifier {
     Bold,
@@ -12,6 +125,11 @@ pub enum Modifier {
     Italic,
     Underline,
     Reverse,
+    Strikethrough,
+    Blink,
+    Hidden,
+    DoubleUnderline,
+    CurlyUnderline,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -33,13 +151,31 @@ impl ModifierSet {
     pub fn contains(self, modifier: Modifier) -> bool {
         self.bits & modifier.bit() != 0
     }
+
+    pub fn union(self, other: ModifierSet) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    pub fn difference(self, other: ModifierSet) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Style {
     pub fg: Option<Color>,
     pub bg: Option<Color>,
+    pub underline_color: Option<Color>,
     pub modifiers: ModifierSet,
+    pub removed_modifiers: ModifierSet,
 }
 
 impl Style {
@@ -57,18 +193,54 @@ impl Style {
         self
     }
 
+    pub fn underline_color(mut self, color: Color) -> Self {
+        self.underline_color = Some(color);
+        self
+    }
+
     pub fn modifier(mut self, modifier: Modifier) -> Self {
         self.modifiers = self.modifiers.insert(modifier);
+        self.removed_modifiers = self.removed_modifiers.remove(modifier);
         self
     }
 
     pub fn remove(mut self, modifier: Modifier) -> Self {
         self.modifiers = self.modifiers.remove(modifier);
+        self.removed_modifiers = self.removed_modifiers.insert(modifier);
         self
     }
+
+    pub fn patch(self, other: Style) -> Self {
+        Self {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            underline_color: other.underline_color.or(self.underline_color),
+            modifiers: self
+                .modifiers
+                .difference(other.removed_modifiers)
+                .union(other.modifiers),
+            removed_modifiers: self
+                .removed_modifiers
+                .difference(other.modifiers)
+                .union(other.removed_modifiers),
+        }
+    }
 }
 
 impl Modifier {
+    pub const ALL: [Modifier; 10] = [
+        Modifier::Bold,
+        Modifier::Dim,
+        Modifier::Italic,
+        Modifier::Underline,
+        Modifier::Reverse,
+        Modifier::Strikethrough,
+        Modifier::Blink,
+        Modifier::Hidden,
+        Modifier::DoubleUnderline,
+        Modifier::CurlyUnderline,
+    ];
+
     fn bit(self) -> u16 {
         match self {
             Modifier::Bold => 1 << 0,
@@ -76,6 +248,11 @@ impl Modifier {
             Modifier::Italic => 1 << 2,
             Modifier::Underline => 1 << 3,
             Modifier::Reverse => 1 << 4,
+            Modifier::Strikethrough => 1 << 5,
+            Modifier::Blink => 1 << 6,
+            Modifier::Hidden => 1 << 7,
+            Modifier::DoubleUnderline => 1 << 8,
+            Modifier::CurlyUnderline => 1 << 9,
         }
     }
 }
@@ -84,6 +261,38 @@ impl Modifier {
 mod tests {
     use super::{Color, Modifier, Style};
 
+    #[test]
+    fn color_parses_hex_names_and_ansi() {
+        assert_eq!("#1e90ff".parse(), Ok(Color::Rgb(30, 144, 255)));
+        assert_eq!("#FFF".parse(), Ok(Color::Rgb(255, 255, 255)));
+        assert_eq!("red".parse(), Ok(Color::Red));
+        assert_eq!("Bright-Blue".parse(), Ok(Color::BrightBlue));
+        assert_eq!("ansi:39".parse(), Ok(Color::Ansi(39)));
+        assert_eq!("default".parse(), Ok(Color::Default));
+    }
+
+    #[test]
+    fn color_parse_rejects_malformed_input() {
+        assert!("#12345".parse::<Color>().is_err());
+        assert!("#gggggg".parse::<Color>().is_err());
+        assert!("ansi:256".parse::<Color>().is_err());
+        assert!("reddish".parse::<Color>().is_err());
+        assert!("".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn color_display_round_trips_through_from_str() {
+        for color in [
+            Color::Default,
+            Color::BrightMagenta,
+            Color::Ansi(200),
+            Color::Rgb(1, 128, 255),
+        ] {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+        assert_eq!(Color::Rgb(30, 144, 255).to_string(), "#1e90ff");
+    }
+
     #[test]
     fn style_builder_sets_colors_and_modifiers() {
         let style = Style::new()
@@ -98,6 +307,22 @@ mod tests {
         assert!(style.modifiers.contains(Modifier::Underline));
     }
 
+    #[test]
+    fn extended_modifiers_use_distinct_flags() {
+        let style = Style::new()
+            .modifier(Modifier::Strikethrough)
+            .modifier(Modifier::CurlyUnderline)
+            .underline_color(Color::Ansi(196));
+
+        assert!(style.modifiers.contains(Modifier::Strikethrough));
+        assert!(style.modifiers.contains(Modifier::CurlyUnderline));
+        assert!(!style.modifiers.contains(Modifier::Underline));
+        assert!(!style.modifiers.contains(Modifier::DoubleUnderline));
+        assert!(!style.modifiers.contains(Modifier::Blink));
+        assert!(!style.modifiers.contains(Modifier::Hidden));
+        assert_eq!(style.underline_color, Some(Color::Ansi(196)));
+    }
+
     #[test]
     fn remove_modifier_clears_flag_only() {
         let style = Style::new()
@@ -108,4 +333,82 @@ mod tests {
         assert!(!style.modifiers.contains(Modifier::Bold));
         assert!(style.modifiers.contains(Modifier::Italic));
     }
+
+    #[test]
+    fn patch_inherits_unset_colors() {
+        let base = Style::new().fg(Color::Ansi(1)).bg(Color::Ansi(2));
+        let patched = base.patch(Style::new().fg(Color::Ansi(3)));
+
+        assert_eq!(patched.fg, Some(Color::Ansi(3)));
+        assert_eq!(patched.bg, Some(Color::Ansi(2)));
+    }
+
+    #[test]
+    fn patch_inherits_underline_color() {
+        let base = Style::new().underline_color(Color::Ansi(196));
+
+        assert_eq!(
+            base.patch(Style::new().modifier(Modifier::CurlyUnderline))
+                .underline_color,
+            Some(Color::Ansi(196))
+        );
+        assert_eq!(
+            base.patch(Style::new().underline_color(Color::Ansi(33)))
+                .underline_color,
+            Some(Color::Ansi(33))
+        );
+    }
+
+    #[test]
+    fn patch_adds_and_removes_modifiers() {
+        let base = Style::new()
+            .modifier(Modifier::Bold)
+            .modifier(Modifier::Italic);
+        let patched = base.patch(
+            Style::new()
+                .modifier(Modifier::Underline)
+                .remove(Modifier::Bold),
+        );
+
+        assert!(!patched.modifiers.contains(Modifier::Bold));
+        assert!(patched.modifiers.contains(Modifier::Italic));
+        assert!(patched.modifiers.contains(Modifier::Underline));
+    }
+
+    #[test]
+    fn patch_with_default_style_is_identity() {
+        let base = Style::new()
+            .fg(Color::Rgb(1, 2, 3))
+            .modifier(Modifier::Reverse);
+
+        assert_eq!(base.patch(Style::default()), base);
+    }
+
+    #[test]
+    fn all_lists_every_modifier_once() {
+        for (idx, modifier) in Modifier::ALL.into_iter().enumerate() {
+            let position = match modifier {
+                Modifier::Bold => 0,
+                Modifier::Dim => 1,
+                Modifier::Italic => 2,
+                Modifier::Underline => 3,
+                Modifier::Reverse => 4,
+                Modifier::Strikethrough => 5,
+                Modifier::Blink => 6,
+                Modifier::Hidden => 7,
+                Modifier::DoubleUnderline => 8,
+                Modifier::CurlyUnderline => 9,
+            };
+            assert_eq!(position, idx);
+        }
+    }
+
+    #[test]
+    fn patch_reenables_previously_removed_modifier() {
+        let base = Style::new().remove(Modifier::Dim);
+        let patched = base.patch(Style::new().modifier(Modifier::Dim));
+
+        assert!(patched.modifiers.contains(Modifier::Dim));
+        assert!(!patched.removed_modifiers.contains(Modifier::Dim));
+    }
 }
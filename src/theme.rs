@@ -68,6 +68,7 @@ struct ThemeFile {
 struct StyleSpec {
     fg: Option<ColorSpec>,
     bg: Option<ColorSpec>,
+    underline_color: Option<ColorSpec>,
     modifiers: Option<Vec<ModifierSpec>>,
 }
 
@@ -83,6 +84,10 @@ impl StyleSpec {
             style = style.bg(color.into_color()?);
         }
 
+        if let Some(color) = self.underline_color {
+            style = style.underline_color(color.into_color()?);
+        }
+
         if let Some(modifiers) = self.modifiers {
             for modifier in modifiers {
                 style = style.modifier(modifier.into_modifier());
@@ -96,6 +101,7 @@ impl StyleSpec {
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum ColorSpec {
+    Text(String),
     Default(DefaultColorSpec),
     Ansi(AnsiColorSpec),
     Rgb(RgbColorSpec),
@@ -122,6 +128,9 @@ struct RgbColorSpec {
 impl ColorSpec {
     fn into_color(self) -> Result<Color, ThemeError> {
         match self {
+            ColorSpec::Text(text) => text
+                .parse()
+                .map_err(|err| ThemeError::Invalid(format!("{err}"))),
             ColorSpec::Default(DefaultColorSpec { default: true }) => Ok(Color::Default),
             ColorSpec::Default(DefaultColorSpec { default: false }) => Err(ThemeError::Invalid(
                 "`default` color must be true".to_string(),
@@ -133,13 +142,18 @@ impl ColorSpec {
 }
 
 #[derive(Debug, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 enum ModifierSpec {
     Bold,
     Dim,
     Italic,
     Underline,
     Reverse,
+    Strikethrough,
+    Blink,
+    Hidden,
+    DoubleUnderline,
+    CurlyUnderline,
 }
 
 impl ModifierSpec {
@@ -150,6 +164,11 @@ impl ModifierSpec {
             ModifierSpec::Italic => Modifier::Italic,
             ModifierSpec::Underline => Modifier::Underline,
             ModifierSpec::Reverse => Modifier::Reverse,
+            ModifierSpec::Strikethrough => Modifier::Strikethrough,
+            ModifierSpec::Blink => Modifier::Blink,
+            ModifierSpec::Hidden => Modifier::Hidden,
+            ModifierSpec::DoubleUnderline => Modifier::DoubleUnderline,
+            ModifierSpec::CurlyUnderline => Modifier::CurlyUnderline,
         }
     }
 }
@@ -184,6 +203,63 @@ mod tests {
         assert!(selected.modifiers.contains(Modifier::Underline));
     }
 
+    #[test]
+    fn parses_extended_modifiers_and_underline_color() {
+        let input = r#"
+        {
+          "tokens": {
+            "diff.deleted": { "modifiers": ["strikethrough", "dim"] },
+            "spell.error": {
+              "underline_color": { "ansi": 196 },
+              "modifiers": ["curly_underline"]
+            }
+          }
+        }
+        "#;
+
+        let theme = Theme::from_json_str(input).expect("theme should parse");
+        let deleted = theme.style("diff.deleted").expect("token should exist");
+        let spell = theme.style("spell.error").expect("token should exist");
+
+        assert!(deleted.modifiers.contains(Modifier::Strikethrough));
+        assert!(spell.modifiers.contains(Modifier::CurlyUnderline));
+        assert_eq!(spell.underline_color, Some(Color::Ansi(196)));
+    }
+
+    #[test]
+    fn parses_string_color_forms() {
+        let input = r##"
+        {
+          "tokens": {
+            "a": { "fg": "#1e90ff", "bg": "red" },
+            "b": { "fg": "ansi:39", "bg": "bright_black" }
+          }
+        }
+        "##;
+
+        let theme = Theme::from_json_str(input).expect("theme should parse");
+        let a = theme.style("a").expect("token should exist");
+        let b = theme.style("b").expect("token should exist");
+
+        assert_eq!(a.fg, Some(Color::Rgb(30, 144, 255)));
+        assert_eq!(a.bg, Some(Color::Red));
+        assert_eq!(b.fg, Some(Color::Ansi(39)));
+        assert_eq!(b.bg, Some(Color::BrightBlack));
+    }
+
+    #[test]
+    fn invalid_color_string_fails_strictly() {
+        let input = r##"
+        {
+          "tokens": {
+            "x": { "fg": "#12" }
+          }
+        }
+        "##;
+
+        assert!(Theme::from_json_str(input).is_err());
+    }
+
     #[test]
     fn token_lookup_returns_none_when_missing() {
         let input = r#"{ "tokens": {} }"#;
@@ -197,7 +273,7 @@ mod tests {
         let input = r#"
         {
           "tokens": {
-            "x": { "modifiers": ["blink"] }
+            "x": { "modifiers": ["sparkle"] }
           }
         }
         "#;
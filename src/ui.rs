@@ -1,16 +1,54 @@
-use crate::{Color, Frame, Rect, Style, Theme};
+use std::collections::HashMap;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+use serde::{Deserialize, Serialize};
+
+use crate::{Color, Frame, LayoutError, Rect, Scrollbar, ScrollbarOrientation, Style, Theme};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Direction {
     Horizontal,
     Vertical,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Constraint {
     Fixed(u16),
     Percent(u8),
-    Fill,
+    Ratio(u32, u32),
+    Min(u16),
+    Max(u16),
+    Fill(u16),
+    /// Sized by the `Measure` registered for the node in `resolve_measured`; with no
+    /// measurement it shares free space like `Fill(1)`.
+    Content,
+}
+
+pub trait Measure {
+    fn preferred_width(&self) -> u16;
+
+    fn measure_height(&self, width: u16) -> u16;
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Justify {
+    #[default]
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossAlign {
+    #[default]
+    Start,
+    Center,
+    End,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -61,29 +99,140 @@ impl Padding {
 #[derive(Clone, Debug)]
 pub struct Slot {
     pub constraint: Constraint,
+    pub min: Option<u16>,
+    pub max: Option<u16>,
+    pub cross_size: Option<u16>,
+    pub align: Option<CrossAlign>,
     pub node: LayoutNode,
 }
 
 impl Slot {
     pub fn new(constraint: Constraint, node: LayoutNode) -> Self {
-        Self { constraint, node }
+        Self {
+            constraint,
+            min: None,
+            max: None,
+            cross_size: None,
+            align: None,
+            node,
+        }
+    }
+
+    pub fn min(mut self, min: u16) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: u16) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn cross_size(mut self, size: u16) -> Self {
+        self.cross_size = Some(size);
+        self
+    }
+
+    pub fn align(mut self, align: CrossAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GridCell {
+    pub row: u16,
+    pub column: u16,
+    pub row_span: u16,
+    pub column_span: u16,
+    pub node: LayoutNode,
+}
+
+impl GridCell {
+    pub fn new(row: u16, column: u16, node: LayoutNode) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            node,
+        }
+    }
+
+    pub fn row_span(mut self, span: u16) -> Self {
+        self.row_span = span.max(1);
+        self
+    }
+
+    pub fn column_span(mut self, span: u16) -> Self {
+        self.column_span = span.max(1);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Breakpoint {
+    pub min_width: u16,
+    pub min_height: u16,
+    pub node: LayoutNode,
+}
+
+impl Breakpoint {
+    pub fn new(node: LayoutNode) -> Self {
+        Self {
+            min_width: 0,
+            min_height: 0,
+            node,
+        }
+    }
+
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn min_height(mut self, height: u16) -> Self {
+        self.min_height = height;
+        self
+    }
+
+    fn fits(&self, area: Rect) -> bool {
+        area.width >= self.min_width && area.height >= self.min_height
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct LayoutNode {
-    name: String,
-    padding: Padding,
-    kind: NodeKind,
+    pub(crate) name: String,
+    pub(crate) padding: Padding,
+    pub(crate) kind: NodeKind,
 }
 
 #[derive(Clone, Debug)]
-enum NodeKind {
+pub(crate) enum NodeKind {
     Leaf,
     Split {
         direction: Direction,
         children: Vec<Slot>,
+        spacing: Spacing,
+    },
+    Grid {
+        rows: Vec<Constraint>,
+        columns: Vec<Constraint>,
+        cells: Vec<GridCell>,
+        row_gap: u16,
+        column_gap: u16,
     },
+    Responsive {
+        variants: Vec<Breakpoint>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Spacing {
+    pub(crate) gap: u16,
+    pub(crate) justify: Justify,
+    pub(crate) align: CrossAlign,
 }
 
 impl LayoutNode {
@@ -106,6 +255,39 @@ impl LayoutNode {
             kind: NodeKind::Split {
                 direction,
                 children: children.into_iter().collect(),
+                spacing: Spacing::default(),
+            },
+        }
+    }
+
+    pub fn grid(
+        name: impl Into<String>,
+        rows: impl IntoIterator<Item = Constraint>,
+        columns: impl IntoIterator<Item = Constraint>,
+        cells: impl IntoIterator<Item = GridCell>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            padding: Padding::default(),
+            kind: NodeKind::Grid {
+                rows: rows.into_iter().collect(),
+                columns: columns.into_iter().collect(),
+                cells: cells.into_iter().collect(),
+                row_gap: 0,
+                column_gap: 0,
+            },
+        }
+    }
+
+    pub fn responsive(
+        name: impl Into<String>,
+        variants: impl IntoIterator<Item = Breakpoint>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            padding: Padding::default(),
+            kind: NodeKind::Responsive {
+                variants: variants.into_iter().collect(),
             },
         }
     }
@@ -115,36 +297,199 @@ impl LayoutNode {
         self
     }
 
+    pub fn with_gap(mut self, gap: u16) -> Self {
+        match &mut self.kind {
+            NodeKind::Split { spacing, .. } => spacing.gap = gap,
+            NodeKind::Grid {
+                row_gap,
+                column_gap,
+                ..
+            } => {
+                *row_gap = gap;
+                *column_gap = gap;
+            }
+            NodeKind::Leaf | NodeKind::Responsive { .. } => {}
+        }
+        self
+    }
+
+    pub fn with_row_gap(mut self, gap: u16) -> Self {
+        if let NodeKind::Grid { row_gap, .. } = &mut self.kind {
+            *row_gap = gap;
+        }
+        self
+    }
+
+    pub fn with_column_gap(mut self, gap: u16) -> Self {
+        if let NodeKind::Grid { column_gap, .. } = &mut self.kind {
+            *column_gap = gap;
+        }
+        self
+    }
+
+    pub fn with_justify(mut self, justify: Justify) -> Self {
+        if let NodeKind::Split { spacing, .. } = &mut self.kind {
+            spacing.justify = justify;
+        }
+        self
+    }
+
+    pub fn with_align(mut self, align: CrossAlign) -> Self {
+        if let NodeKind::Split { spacing, .. } = &mut self.kind {
+            spacing.align = align;
+        }
+        self
+    }
+
     pub fn resolve(&self, area: Rect) -> ResolvedLayout {
+        self.resolve_measured(area, &[])
+    }
+
+    pub fn resolve_measured(
+        &self,
+        area: Rect,
+        measures: &[(&str, &dyn Measure)],
+    ) -> ResolvedLayout {
         let mut zones = Vec::new();
-        resolve_node(self, area, &mut zones);
-        ResolvedLayout { zones }
+        let mut hidden = Vec::new();
+        resolve_node(self, "", area, measures, &mut zones, &mut hidden);
+
+        let mut index = HashMap::with_capacity(zones.len() * 2);
+        for (idx, zone) in zones.iter().enumerate() {
+            index.insert(zone.name.clone(), idx);
+        }
+        for (idx, zone) in zones.iter().enumerate() {
+            index.insert(zone.path.clone(), idx);
+        }
+        hidden.retain(|name| !index.contains_key(name));
+        hidden.sort();
+        hidden.dedup();
+        ResolvedLayout {
+            zones,
+            hidden,
+            index,
+        }
+    }
+
+    fn collect_names(&self, names: &mut Vec<String>) {
+        names.push(self.name.clone());
+        match &self.kind {
+            NodeKind::Leaf => {}
+            NodeKind::Split { children, .. } => {
+                for slot in children {
+                    slot.node.collect_names(names);
+                }
+            }
+            NodeKind::Grid { cells, .. } => {
+                for cell in cells {
+                    cell.node.collect_names(names);
+                }
+            }
+            NodeKind::Responsive { variants } => {
+                for variant in variants {
+                    variant.node.collect_names(names);
+                }
+            }
+        }
     }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Zone {
     pub name: String,
+    pub path: String,
     pub area: Rect,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct ResolvedLayout {
     zones: Vec<Zone>,
+    hidden: Vec<String>,
+    index: HashMap<String, usize>,
 }
 
 impl ResolvedLayout {
-    pub fn area(&self, name: &str) -> Option<Rect> {
-        self.zones
-            .iter()
-            .rev()
-            .find(|zone| zone.name == name)
-            .map(|zone| zone.area)
+    pub fn area(&self, key: &str) -> Option<Rect> {
+        self.zone(key).map(|zone| zone.area)
+    }
+
+    pub fn zone(&self, key: &str) -> Option<&Zone> {
+        if let Some(&idx) = self.index.get(key) {
+            return self.zones.get(idx);
+        }
+        if !key.contains('/') {
+            return None;
+        }
+
+        self.zones.iter().rev().find(|zone| {
+            zone.path
+                .strip_suffix(key)
+                .is_some_and(|prefix| prefix.ends_with('/'))
+        })
     }
 
     pub fn zones(&self) -> &[Zone] {
         &self.zones
     }
+
+    pub fn subtree(&self, key: &str) -> impl Iterator<Item = &Zone> {
+        let root = self.zone(key).map(|zone| zone.path.as_str());
+        self.zones.iter().filter(move |zone| {
+            root.is_some_and(|root| {
+                zone.path
+                    .strip_prefix(root)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), LayoutError> {
+        let mut issues = Vec::new();
+        let mut names = Vec::new();
+        let mut seen: HashMap<&str, Vec<&str>> = HashMap::with_capacity(self.zones.len());
+        for zone in &self.zones {
+            if zone.name.is_empty() {
+                issues.push(format!("empty zone name at `{}`", zone.path));
+                continue;
+            }
+            if zone.name.contains('/') {
+                issues.push(format!(
+                    "zone name `{}` at `{}` contains `/`",
+                    zone.name, zone.path
+                ));
+            }
+            let paths = seen.entry(&zone.name).or_insert_with(|| {
+                names.push(zone.name.as_str());
+                Vec::new()
+            });
+            paths.push(&zone.path);
+        }
+        for name in names {
+            let paths = &seen[name];
+            if paths.len() > 1 {
+                let paths = paths
+                    .iter()
+                    .map(|path| format!("`{path}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                issues.push(format!("duplicate zone name `{name}` at {paths}"));
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(LayoutError::Invalid(issues.join("; ")))
+        }
+    }
+
+    pub fn is_hidden(&self, name: &str) -> bool {
+        self.hidden.iter().any(|hidden| hidden == name)
+    }
+
+    pub fn hidden(&self) -> &[String] {
+        &self.hidden
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -193,6 +538,18 @@ impl Text {
     }
 }
 
+impl Measure for Text {
+    fn preferred_width(&self) -> u16 {
+        let width = text_width(self.content.lines().map(|line| line.chars().count()));
+        width.saturating_add(horizontal_insets(&[self.padding, self.margin]))
+    }
+
+    fn measure_height(&self, _width: u16) -> u16 {
+        clamp_len(self.content.lines().count())
+            .saturating_add(vertical_insets(&[self.padding, self.margin]))
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum WrapMode {
     #[default]
@@ -267,6 +624,19 @@ impl Paragraph {
     }
 }
 
+impl Measure for Paragraph {
+    fn preferred_width(&self) -> u16 {
+        let width = text_width(self.content.lines().map(|line| line.chars().count()));
+        width.saturating_add(horizontal_insets(&[self.padding, self.margin]))
+    }
+
+    fn measure_height(&self, width: u16) -> u16 {
+        let inner = width.saturating_sub(horizontal_insets(&[self.padding, self.margin]));
+        clamp_len(wrap_lines(&self.content, inner as usize, self.wrap).len())
+            .saturating_add(vertical_insets(&[self.padding, self.margin]))
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StatusBar {
     left: String,
@@ -353,6 +723,18 @@ impl StatusBar {
     }
 }
 
+impl Measure for StatusBar {
+    fn preferred_width(&self) -> u16 {
+        let gap = usize::from(!self.left.is_empty() && !self.right.is_empty());
+        clamp_len(self.left.chars().count() + gap + self.right.chars().count())
+            .saturating_add(horizontal_insets(&[self.margin]))
+    }
+
+    fn measure_height(&self, _width: u16) -> u16 {
+        1_u16.saturating_add(vertical_insets(&[self.margin]))
+    }
+}
+
 impl Default for StatusBar {
     fn default() -> Self {
         Self::new()
@@ -495,7 +877,6 @@ impl Input {
         } else {
             self.style
         };
-        let cursor_style = self.cursor_style.unwrap_or(base_style);
         let placeholder_style = self.placeholder_style.unwrap_or(base_style);
 
         let mut row = " ".repeat(width);
@@ -515,13 +896,11 @@ impl Input {
             }
 
             if self.focused {
-                let cursor_x = self.cursor.min(width.saturating_sub(1));
-                let cursor_ch = if self.value.is_empty() {
-                    clipped.chars().nth(cursor_x).unwrap_or(' ')
-                } else {
-                    self.value.chars().nth(cursor_x).unwrap_or(' ')
-                };
-                frame.print_styled(cursor_x as u16, 0, &cursor_ch.to_string(), cursor_style);
+                let cursor_x = self.cursor.min(width.saturating_sub(1)) as u16;
+                if let Some(style) = self.cursor_style {
+                    frame.set_style(Rect::new(cursor_x, 0, 1, 1), style);
+                }
+                frame.set_cursor(cursor_x, 0);
             }
         });
     }
@@ -1082,58 +1461,37 @@ impl Block {
         }
 
         let body_style = self.body_style.unwrap_or(self.style);
-        fill_with_style(frame, area, body_style);
+        frame.fill(area, ' ', body_style);
 
         let border_style = self.border_style.unwrap_or(self.style);
         let title_style = self.title_style.unwrap_or(border_style);
-        let glyphs = border_glyphs(self.border_type);
+        let border_type = self.border_type;
+        let glyphs = border_glyphs(border_type);
 
         frame.render_in(area, |frame| {
-            let left_x = 0;
             let right_x = area.width.saturating_sub(1);
-            let top_y = 0;
             let bottom_y = area.height.saturating_sub(1);
+            let inner_width = area.width.saturating_sub(2);
+            let inner_height = area.height.saturating_sub(2);
 
             if self.borders.top {
-                frame.print_styled(left_x, top_y, glyphs.top_left, border_style);
-                if area.width > 2 {
-                    frame.print_styled(
-                        1,
-                        top_y,
-                        &glyphs
-                            .horizontal
-                            .repeat(area.width.saturating_sub(2) as usize),
-                        border_style,
-                    );
-                }
-                frame.print_styled(right_x, top_y, glyphs.top_right, border_style);
+                frame.set_cell(0, 0, glyphs.top_left, border_style);
+                frame.horizontal_line(1, 0, inner_width, border_type, border_style);
+                frame.set_cell(right_x, 0, glyphs.top_right, border_style);
             }
 
             if self.borders.bottom {
-                frame.print_styled(left_x, bottom_y, glyphs.bottom_left, border_style);
-                if area.width > 2 {
-                    frame.print_styled(
-                        1,
-                        bottom_y,
-                        &glyphs
-                            .horizontal
-                            .repeat(area.width.saturating_sub(2) as usize),
-                        border_style,
-                    );
-                }
-                frame.print_styled(right_x, bottom_y, glyphs.bottom_right, border_style);
+                frame.set_cell(0, bottom_y, glyphs.bottom_left, border_style);
+                frame.horizontal_line(1, bottom_y, inner_width, border_type, border_style);
+                frame.set_cell(right_x, bottom_y, glyphs.bottom_right, border_style);
             }
 
             if self.borders.left {
-                for y in 1..area.height.saturating_sub(1) {
-                    frame.print_styled(left_x, y, glyphs.vertical, border_style);
-                }
+                frame.vertical_line(0, 1, inner_height, border_type, border_style);
             }
 
             if self.borders.right {
-                for y in 1..area.height.saturating_sub(1) {
-                    frame.print_styled(right_x, y, glyphs.vertical, border_style);
-                }
+                frame.vertical_line(right_x, 1, inner_height, border_type, border_style);
             }
 
             if let Some(title) = &self.title {
@@ -1160,6 +1518,7 @@ pub struct List {
     item_style: Option<Style>,
     selected_style: Option<Style>,
     selected_prefix: String,
+    scrollbar: Option<Scrollbar>,
     padding: Padding,
     margin: Padding,
 }
@@ -1177,6 +1536,7 @@ impl List {
             item_style: None,
             selected_style: None,
             selected_prefix: "›".to_string(),
+            scrollbar: None,
             padding: Padding::default(),
             margin: Padding::default(),
         }
@@ -1207,6 +1567,11 @@ impl List {
         self
     }
 
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
     pub fn padding(mut self, padding: Padding) -> Self {
         self.padding = padding;
         self
@@ -1224,6 +1589,12 @@ impl List {
         }
 
         let viewport_height = area.height as usize;
+        let (area, track) = reserve_scrollbar(
+            area,
+            self.scrollbar.as_ref(),
+            self.items.len(),
+            viewport_height,
+        );
         let selected = self
             .selected
             .unwrap_or(0)
@@ -1260,6 +1631,25 @@ impl List {
                 );
             }
         });
+        render_scrollbar(
+            frame,
+            self.scrollbar.as_ref(),
+            track,
+            self.items.len(),
+            start,
+        );
+    }
+}
+
+impl Measure for List {
+    fn preferred_width(&self) -> u16 {
+        let marker = self.selected_prefix.chars().count().max(1) + 1;
+        text_width(self.items.iter().map(|item| marker + item.chars().count()))
+            .saturating_add(horizontal_insets(&[self.padding, self.margin]))
+    }
+
+    fn measure_height(&self, _width: u16) -> u16 {
+        clamp_len(self.items.len()).saturating_add(vertical_insets(&[self.padding, self.margin]))
     }
 }
 
@@ -1406,6 +1796,7 @@ pub struct Table {
     row_style: Option<Style>,
     selected_style: Option<Style>,
     border_style: Option<Style>,
+    scrollbar: Option<Scrollbar>,
     padding: Padding,
     margin: Padding,
 }
@@ -1422,6 +1813,7 @@ impl Table {
             row_style: None,
             selected_style: None,
             border_style: None,
+            scrollbar: None,
             padding: Padding::default(),
             margin: Padding::default(),
         }
@@ -1462,6 +1854,11 @@ impl Table {
         self
     }
 
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
     pub fn padding(mut self, padding: Padding) -> Self {
         self.padding = padding;
         self
@@ -1483,13 +1880,25 @@ impl Table {
         let row_style = self.row_style.unwrap_or(base);
         let selected_style = self.selected_style.unwrap_or(row_style);
         let border_style = self.border_style.unwrap_or(header_style);
-
-        let column_slots: Vec<Slot> = self
-            .columns
-            .iter()
-            .map(|col| Slot::new(col.width, LayoutNode::leaf("col")))
-            .collect();
-        let widths = resolve_sizes(area.width, &column_slots);
+        let body_height = area.height.saturating_sub(2) as usize;
+        let (body, track) =
+            reserve_scrollbar(area, self.scrollbar.as_ref(), self.rows.len(), body_height);
+
+        let widths = resolve_tracks(
+            body.width,
+            self.columns.iter().enumerate().map(|(idx, col)| {
+                let content = || {
+                    let cells = self
+                        .rows
+                        .iter()
+                        .filter_map(|row| row.get(idx))
+                        .map(|cell| cell.chars().count());
+                    Some(text_width(cells.chain([col.title.chars().count()])))
+                };
+                (measured_constraint(col.width, content), (None, None))
+            }),
+            true,
+        );
 
         frame.render_in(area, |frame| {
             frame.print_styled(0, 0, &" ".repeat(area.width as usize), header_style);
@@ -1508,7 +1917,6 @@ impl Table {
                 frame.print_styled(0, 1, &"─".repeat(area.width as usize), border_style);
             }
 
-            let body_height = area.height.saturating_sub(2) as usize;
             if body_height == 0 || self.rows.is_empty() {
                 return;
             }
@@ -1521,13 +1929,20 @@ impl Table {
                 .scroll
                 .unwrap_or_else(|| scroll_start(selected, body_height, self.rows.len()));
             let end = (start + body_height).min(self.rows.len());
+            render_scrollbar(
+                frame,
+                self.scrollbar.as_ref(),
+                track.map(|track| Rect::new(track.x - area.x, 2, 1, body_height as u16)),
+                self.rows.len(),
+                start,
+            );
 
             for (row_y, row_idx) in (start..end).enumerate() {
                 let y = (row_y + 2) as u16;
                 frame.print_styled(
                     0,
                     y,
-                    &" ".repeat(area.width as usize),
+                    &" ".repeat(body.width as usize),
                     if row_idx == selected {
                         selected_style
                     } else {
@@ -1762,6 +2177,25 @@ impl Select {
     }
 }
 
+impl Measure for Select {
+    fn preferred_width(&self) -> u16 {
+        let options = self.options.iter().map(|option| option.chars().count() + 3);
+        text_width(options.chain([self.placeholder.chars().count() + 2]))
+            .saturating_add(horizontal_insets(&[self.padding, self.margin]))
+    }
+
+    fn measure_height(&self, _width: u16) -> u16 {
+        let dropdown = if self.expanded {
+            self.options
+                .len()
+                .min(self.max_visible.unwrap_or(self.options.len()))
+        } else {
+            0
+        };
+        clamp_len(1 + dropdown).saturating_add(vertical_insets(&[self.padding, self.margin]))
+    }
+}
+
 impl Default for Select {
     fn default() -> Self {
         Self::new(Vec::<String>::new())
@@ -1889,6 +2323,7 @@ pub struct RadioGroup {
     selected_style: Option<Style>,
     highlight_style: Option<Style>,
     marker_style: Option<Style>,
+    scrollbar: Option<Scrollbar>,
     padding: Padding,
     margin: Padding,
 }
@@ -1909,6 +2344,7 @@ impl RadioGroup {
             selected_style: None,
             highlight_style: None,
             marker_style: None,
+            scrollbar: None,
             padding: Padding::default(),
             margin: Padding::default(),
         }
@@ -1954,6 +2390,11 @@ impl RadioGroup {
         self
     }
 
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
     pub fn padding(mut self, padding: Padding) -> Self {
         self.padding = padding;
         self
@@ -1970,7 +2411,6 @@ impl RadioGroup {
             return;
         }
 
-        let width = area.width as usize;
         let base_style = self.style;
         let selected_style = self.selected_style.unwrap_or(base_style);
         let highlight_style = self.highlight_style.unwrap_or(selected_style);
@@ -1986,6 +2426,9 @@ impl RadioGroup {
         let rows = viewport.min(max_visible.max(1));
         let start = scroll_start(highlighted, rows, self.options.len());
         let end = (start + rows).min(self.options.len());
+        let (area, track) =
+            reserve_scrollbar(area, self.scrollbar.as_ref(), self.options.len(), rows);
+        let width = area.width as usize;
 
         frame.render_in(area, |frame| {
             for (row_idx, option_idx) in (start..end).enumerate() {
@@ -2017,6 +2460,28 @@ impl RadioGroup {
                 }
             }
         });
+        render_scrollbar(
+            frame,
+            self.scrollbar.as_ref(),
+            track.map(|track| Rect::new(track.x, track.y, 1, rows as u16)),
+            self.options.len(),
+            start,
+        );
+    }
+}
+
+impl Measure for RadioGroup {
+    fn preferred_width(&self) -> u16 {
+        text_width(self.options.iter().map(|option| option.chars().count() + 3))
+            .saturating_add(horizontal_insets(&[self.padding, self.margin]))
+    }
+
+    fn measure_height(&self, _width: u16) -> u16 {
+        let rows = self
+            .options
+            .len()
+            .min(self.max_visible.unwrap_or(self.options.len()));
+        clamp_len(rows).saturating_add(vertical_insets(&[self.padding, self.margin]))
     }
 }
 
@@ -2517,6 +2982,7 @@ pub struct MultiSelect {
     selected_style: Option<Style>,
     highlight_style: Option<Style>,
     marker_style: Option<Style>,
+    scrollbar: Option<Scrollbar>,
     padding: Padding,
     margin: Padding,
 }
@@ -2537,6 +3003,7 @@ impl MultiSelect {
             selected_style: None,
             highlight_style: None,
             marker_style: None,
+            scrollbar: None,
             padding: Padding::default(),
             margin: Padding::default(),
         }
@@ -2582,6 +3049,11 @@ impl MultiSelect {
         self
     }
 
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
     pub fn padding(mut self, padding: Padding) -> Self {
         self.padding = padding;
         self
@@ -2598,7 +3070,6 @@ impl MultiSelect {
             return;
         }
 
-        let width = area.width as usize;
         let base_style = self.style;
         let selected_style = self.selected_style.unwrap_or(base_style);
         let highlight_style = self.highlight_style.unwrap_or(selected_style);
@@ -2610,6 +3081,9 @@ impl MultiSelect {
         let rows = viewport.min(max_visible.max(1));
         let start = scroll_start(highlighted, rows, self.options.len());
         let end = (start + rows).min(self.options.len());
+        let (area, track) =
+            reserve_scrollbar(area, self.scrollbar.as_ref(), self.options.len(), rows);
+        let width = area.width as usize;
 
         frame.render_in(area, |frame| {
             for (row_idx, option_idx) in (start..end).enumerate() {
@@ -2639,6 +3113,28 @@ impl MultiSelect {
                 }
             }
         });
+        render_scrollbar(
+            frame,
+            self.scrollbar.as_ref(),
+            track.map(|track| Rect::new(track.x, track.y, 1, rows as u16)),
+            self.options.len(),
+            start,
+        );
+    }
+}
+
+impl Measure for MultiSelect {
+    fn preferred_width(&self) -> u16 {
+        text_width(self.options.iter().map(|option| option.chars().count() + 5))
+            .saturating_add(horizontal_insets(&[self.padding, self.margin]))
+    }
+
+    fn measure_height(&self, _width: u16) -> u16 {
+        let rows = self
+            .options
+            .len()
+            .min(self.max_visible.unwrap_or(self.options.len()));
+        clamp_len(rows).saturating_add(vertical_insets(&[self.padding, self.margin]))
     }
 }
 
@@ -2766,49 +3262,36 @@ impl FormField {
 }
 
 #[derive(Clone, Copy)]
-struct BorderGlyphs {
-    horizontal: &'static str,
-    vertical: &'static str,
-    top_left: &'static str,
-    top_right: &'static str,
-    bottom_left: &'static str,
-    bottom_right: &'static str,
+pub(crate) struct BorderGlyphs {
+    pub(crate) horizontal: char,
+    pub(crate) vertical: char,
+    pub(crate) top_left: char,
+    pub(crate) top_right: char,
+    pub(crate) bottom_left: char,
+    pub(crate) bottom_right: char,
 }
 
-fn border_glyphs(border_type: BorderType) -> BorderGlyphs {
+pub(crate) fn border_glyphs(border_type: BorderType) -> BorderGlyphs {
     match border_type {
         BorderType::Unicode => BorderGlyphs {
-            horizontal: "─",
-            vertical: "│",
-            top_left: "┌",
-            top_right: "┐",
-            bottom_left: "└",
-            bottom_right: "┘",
+            horizontal: '─',
+            vertical: '│',
+            top_left: '┌',
+            top_right: '┐',
+            bottom_left: '└',
+            bottom_right: '┘',
         },
         BorderType::Ascii => BorderGlyphs {
-            horizontal: "-",
-            vertical: "|",
-            top_left: "+",
-            top_right: "+",
-            bottom_left: "+",
-            bottom_right: "+",
+            horizontal: '-',
+            vertical: '|',
+            top_left: '+',
+            top_right: '+',
+            bottom_left: '+',
+            bottom_right: '+',
         },
     }
 }
 
-fn fill_with_style(frame: &mut Frame, area: Rect, style: Style) {
-    if area.width == 0 || area.height == 0 {
-        return;
-    }
-
-    let line = " ".repeat(area.width as usize);
-    frame.render_in(area, |f| {
-        for y in 0..area.height {
-            f.print_styled(0, y, &line, style);
-        }
-    });
-}
-
 fn wrap_lines(text: &str, width: usize, wrap: WrapMode) -> Vec<String> {
     if width == 0 {
         return Vec::new();
@@ -2877,6 +3360,26 @@ fn wrap_lines(text: &str, width: usize, wrap: WrapMode) -> Vec<String> {
     }
 }
 
+fn text_width(widths: impl IntoIterator<Item = usize>) -> u16 {
+    clamp_len(widths.into_iter().max().unwrap_or(0))
+}
+
+fn clamp_len(len: usize) -> u16 {
+    len.min(u16::MAX as usize) as u16
+}
+
+fn horizontal_insets(insets: &[Padding]) -> u16 {
+    insets.iter().fold(0, |total: u16, inset| {
+        total.saturating_add(inset.left).saturating_add(inset.right)
+    })
+}
+
+fn vertical_insets(insets: &[Padding]) -> u16 {
+    insets.iter().fold(0, |total: u16, inset| {
+        total.saturating_add(inset.top).saturating_add(inset.bottom)
+    })
+}
+
 fn truncate_to_width(input: &str, width: usize) -> String {
     input.chars().take(width).collect()
 }
@@ -2910,6 +3413,37 @@ fn replace_segment(target: &mut String, start: usize, segment: &str) {
     *target = chars.into_iter().collect();
 }
 
+fn reserve_scrollbar(
+    area: Rect,
+    scrollbar: Option<&Scrollbar>,
+    content: usize,
+    viewport: usize,
+) -> (Rect, Option<Rect>) {
+    match scrollbar {
+        Some(_) if content > viewport && area.width > 1 => (
+            Rect::new(area.x, area.y, area.width - 1, area.height),
+            Some(Rect::new(area.right() - 1, area.y, 1, area.height)),
+        ),
+        _ => (area, None),
+    }
+}
+
+fn render_scrollbar(
+    frame: &mut Frame,
+    scrollbar: Option<&Scrollbar>,
+    track: Option<Rect>,
+    content: usize,
+    offset: usize,
+) {
+    if let (Some(scrollbar), Some(track)) = (scrollbar, track) {
+        scrollbar
+            .clone()
+            .orientation(ScrollbarOrientation::Vertical)
+            .position(content, track.height as usize, offset)
+            .render(frame, track);
+    }
+}
+
 fn scroll_start(selected: usize, viewport_height: usize, len: usize) -> usize {
     if viewport_height == 0 || len == 0 {
         return 0;
@@ -2920,110 +3454,443 @@ fn scroll_start(selected: usize, viewport_height: usize, len: usize) -> usize {
     follow.min(max_start)
 }
 
-fn resolve_node(node: &LayoutNode, area: Rect, zones: &mut Vec<Zone>) {
+fn resolve_node(
+    node: &LayoutNode,
+    parent: &str,
+    area: Rect,
+    measures: &[(&str, &dyn Measure)],
+    zones: &mut Vec<Zone>,
+    hidden: &mut Vec<String>,
+) {
     let area = node.padding.apply(area);
+    let path = if parent.is_empty() {
+        node.name.clone()
+    } else {
+        format!("{parent}/{}", node.name)
+    };
     zones.push(Zone {
         name: node.name.clone(),
+        path: path.clone(),
         area,
     });
 
-    let NodeKind::Split {
-        direction,
-        children,
-    } = &node.kind
-    else {
-        return;
-    };
-
-    let pieces = split_area(area, *direction, children);
-    for (slot, child_area) in children.iter().zip(pieces.into_iter()) {
-        resolve_node(&slot.node, child_area, zones);
-    }
-}
-
-fn split_area(area: Rect, direction: Direction, children: &[Slot]) -> Vec<Rect> {
-    if children.is_empty() {
-        return Vec::new();
-    }
-
-    let total = match direction {
-        Direction::Horizontal => area.width,
-        Direction::Vertical => area.height,
-    };
-
-    let sizes = resolve_sizes(total, children);
-    let mut cursor_x = area.x;
-    let mut cursor_y = area.y;
+    match &node.kind {
+        NodeKind::Leaf => {}
+        NodeKind::Split {
+            direction,
+            children,
+            spacing,
+        } => {
+            let pieces = split_area(area, *direction, children, *spacing, measures);
+            for (slot, child_area) in children.iter().zip(pieces) {
+                resolve_node(&slot.node, &path, child_area, measures, zones, hidden);
+            }
+        }
+        NodeKind::Grid {
+            rows,
+            columns,
+            cells,
+            row_gap,
+            column_gap,
+        } => {
+            let columns = grid_tracks(area.x, area.width, columns, *column_gap, |column| {
+                cells
+                    .iter()
+                    .filter(|cell| cell.column as usize == column && cell.column_span == 1)
+                    .filter_map(|cell| measure_node(&cell.node, measures, Direction::Horizontal, 0))
+                    .max()
+            });
+            let rows = grid_tracks(area.y, area.height, rows, *row_gap, |row| {
+                cells
+                    .iter()
+                    .filter(|cell| cell.row as usize == row && cell.row_span == 1)
+                    .filter_map(|cell| {
+                        let (_, width) = span_tracks(&columns, cell.column, cell.column_span);
+                        measure_node(&cell.node, measures, Direction::Vertical, width)
+                    })
+                    .max()
+            });
+            for cell in cells {
+                let (y, height) = span_tracks(&rows, cell.row, cell.row_span);
+                let (x, width) = span_tracks(&columns, cell.column, cell.column_span);
+                resolve_node(
+                    &cell.node,
+                    &path,
+                    Rect::new(x, y, width, height),
+                    measures,
+                    zones,
+                    hidden,
+                );
+            }
+        }
+        NodeKind::Responsive { variants } => {
+            let chosen = variants
+                .iter()
+                .position(|variant| variant.fits(area))
+                .or(variants.len().checked_sub(1));
+            for (idx, variant) in variants.iter().enumerate() {
+                if Some(idx) == chosen {
+                    resolve_node(&variant.node, &path, area, measures, zones, hidden);
+                } else {
+                    variant.node.collect_names(hidden);
+                }
+            }
+        }
+    }
+}
+
+fn grid_tracks(
+    start: u16,
+    total: u16,
+    constraints: &[Constraint],
+    gap: u16,
+    measure: impl Fn(usize) -> Option<u16>,
+) -> Vec<(u16, u16)> {
+    let gaps =
+        gap.saturating_mul(constraints.len().saturating_sub(1).min(u16::MAX as usize) as u16);
+    let sizes = resolve_tracks(
+        total.saturating_sub(gaps),
+        constraints.iter().enumerate().map(|(idx, constraint)| {
+            (
+                measured_constraint(*constraint, || measure(idx)),
+                (None, None),
+            )
+        }),
+        true,
+    );
+
+    let mut cursor = start;
+    sizes
+        .into_iter()
+        .map(|size| {
+            let track = (cursor, size);
+            cursor = cursor.saturating_add(size).saturating_add(gap);
+            track
+        })
+        .collect()
+}
+
+fn span_tracks(tracks: &[(u16, u16)], index: u16, span: u16) -> (u16, u16) {
+    let first = index as usize;
+    let Some(&(start, _)) = tracks.get(first) else {
+        let end = tracks
+            .last()
+            .map_or(0, |(start, size)| start.saturating_add(*size));
+        return (end, 0);
+    };
+
+    let last = (first + span.max(1) as usize).min(tracks.len()) - 1;
+    let (last_start, last_size) = tracks[last];
+    (start, last_start.saturating_add(last_size) - start)
+}
+
+fn split_area(
+    area: Rect,
+    direction: Direction,
+    children: &[Slot],
+    spacing: Spacing,
+    measures: &[(&str, &dyn Measure)],
+) -> Vec<Rect> {
+    if children.is_empty() {
+        return Vec::new();
+    }
+
+    let (main_start, main_total, cross_start, cross_total) = match direction {
+        Direction::Horizontal => (area.x, area.width, area.y, area.height),
+        Direction::Vertical => (area.y, area.height, area.x, area.width),
+    };
+
+    let gaps = spacing
+        .gap
+        .saturating_mul(children.len().saturating_sub(1).min(u16::MAX as usize) as u16);
+    let available = main_total.saturating_sub(gaps);
+    let sizes = resolve_sizes(
+        available,
+        children,
+        spacing.justify == Justify::Start,
+        |slot| {
+            let cross_size = slot.cross_size.unwrap_or(cross_total).min(cross_total);
+            measure_node(&slot.node, measures, direction, cross_size)
+        },
+    );
+    let leftover = available.saturating_sub(sizes.iter().sum::<u16>());
+    let spaces = justify_spaces(leftover, children.len(), spacing.justify);
+
+    let mut cursor = main_start;
     let mut pieces = Vec::with_capacity(children.len());
+    for (idx, (slot, size)) in children.iter().zip(sizes).enumerate() {
+        cursor = cursor.saturating_add(spaces[idx] as u16);
+        if idx > 0 {
+            cursor = cursor.saturating_add(spacing.gap);
+        }
+
+        let cross_size = slot.cross_size.unwrap_or(cross_total).min(cross_total);
+        let free = cross_total - cross_size;
+        let cross_offset = match slot.align.unwrap_or(spacing.align) {
+            CrossAlign::Start => 0,
+            CrossAlign::Center => free / 2,
+            CrossAlign::End => free,
+        };
+        let cross = cross_start.saturating_add(cross_offset);
 
-    for size in sizes {
         let rect = match direction {
-            Direction::Horizontal => {
-                let rect = Rect::new(cursor_x, area.y, size, area.height);
-                cursor_x = cursor_x.saturating_add(size);
-                rect
-            }
-            Direction::Vertical => {
-                let rect = Rect::new(area.x, cursor_y, area.width, size);
-                cursor_y = cursor_y.saturating_add(size);
-                rect
-            }
+            Direction::Horizontal => Rect::new(cursor, cross, size, cross_size),
+            Direction::Vertical => Rect::new(cross, cursor, cross_size, size),
         };
+        cursor = cursor.saturating_add(size);
         pieces.push(rect);
     }
 
     pieces
 }
 
-fn resolve_sizes(total: u16, children: &[Slot]) -> Vec<u16> {
-    let mut sizes = vec![0_u16; children.len()];
-    let mut remaining = total;
+fn justify_spaces(leftover: u16, count: usize, justify: Justify) -> Vec<u32> {
+    let mut weights = vec![0_u32; count + 1];
+    match justify {
+        Justify::Start => weights[count] = 1,
+        Justify::End => weights[0] = 1,
+        Justify::Center => {
+            weights[0] = 1;
+            weights[count] = 1;
+        }
+        Justify::SpaceBetween if count > 1 => {
+            for weight in &mut weights[1..count] {
+                *weight = 1;
+            }
+        }
+        Justify::SpaceBetween => weights[count] = 1,
+        Justify::SpaceAround => {
+            for weight in &mut weights[1..count] {
+                *weight = 2;
+            }
+            weights[0] = 1;
+            weights[count] = 1;
+        }
+    }
+
+    distribute(leftover as u32, &weights)
+}
+
+struct Track {
+    size: u32,
+    min: u32,
+    max: u32,
+    cap: u32,
+    weight: u32,
+}
+
+impl Track {
+    fn new(total: u32, constraint: Constraint, bounds: (Option<u16>, Option<u16>)) -> Self {
+        let (base, min, max, weight) = match constraint {
+            Constraint::Fixed(value) => (value as u32, value as u32, value as u32, 0),
+            Constraint::Percent(value) => (total * value.min(100) as u32 / 100, 0, u32::MAX, 0),
+            Constraint::Ratio(_, 0) => (0, 0, u32::MAX, 0),
+            Constraint::Ratio(num, den) => (
+                (total as u64 * num.min(den) as u64 / den as u64) as u32,
+                0,
+                u32::MAX,
+                0,
+            ),
+            Constraint::Min(value) => (value as u32, value as u32, u32::MAX, 1),
+            Constraint::Max(value) => (0, 0, value as u32, 1),
+            Constraint::Fill(weight) => (0, 0, u32::MAX, weight as u32),
+            Constraint::Content => (0, 0, u32::MAX, 1),
+        };
+        let cap = match constraint {
+            Constraint::Max(value) => value as u32,
+            _ => u32::MAX,
+        };
 
-    for (idx, slot) in children.iter().enumerate() {
-        if let Constraint::Fixed(value) = slot.constraint {
-            let size = value.min(remaining);
-            sizes[idx] = size;
-            remaining = remaining.saturating_sub(size);
+        let (lower, upper) = bounds;
+        let cap = upper.map_or(cap, |bound| cap.min(bound as u32));
+        let max = upper.map_or(max, |bound| max.min(bound as u32));
+        let min = lower.map_or(min, |bound| min.max(bound as u32));
+        let max = max.max(min);
+        let cap = cap.max(min);
+
+        Self {
+            size: base.clamp(min, max),
+            min,
+            max,
+            cap,
+            weight,
         }
     }
+}
+
+fn resolve_sizes(
+    total: u16,
+    children: &[Slot],
+    absorb_leftover: bool,
+    measure: impl Fn(&Slot) -> Option<u16>,
+) -> Vec<u16> {
+    resolve_tracks(
+        total,
+        children.iter().map(|slot| {
+            (
+                measured_constraint(slot.constraint, || measure(slot)),
+                (slot.min, slot.max),
+            )
+        }),
+        absorb_leftover,
+    )
+}
+
+fn measured_constraint(
+    constraint: Constraint,
+    measure: impl FnOnce() -> Option<u16>,
+) -> Constraint {
+    match constraint {
+        Constraint::Content => measure().map_or(constraint, Constraint::Fixed),
+        _ => constraint,
+    }
+}
 
-    for (idx, slot) in children.iter().enumerate() {
-        if let Constraint::Percent(value) = slot.constraint {
-            let requested = ((total as u32 * value as u32) / 100) as u16;
-            let size = requested.min(remaining);
-            sizes[idx] = size;
-            remaining = remaining.saturating_sub(size);
+fn measure_node(
+    node: &LayoutNode,
+    measures: &[(&str, &dyn Measure)],
+    direction: Direction,
+    cross_size: u16,
+) -> Option<u16> {
+    let (_, measure) = measures.iter().find(|(name, _)| *name == node.name)?;
+    let padding = node.padding;
+    let horizontal = padding.left.saturating_add(padding.right);
+    let vertical = padding.top.saturating_add(padding.bottom);
+
+    Some(match direction {
+        Direction::Horizontal => measure.preferred_width().saturating_add(horizontal),
+        Direction::Vertical => measure
+            .measure_height(cross_size.saturating_sub(horizontal))
+            .saturating_add(vertical),
+    })
+}
+
+fn resolve_tracks(
+    total: u16,
+    constraints: impl IntoIterator<Item = (Constraint, (Option<u16>, Option<u16>))>,
+    absorb_leftover: bool,
+) -> Vec<u16> {
+    let total = total as u32;
+    let mut tracks: Vec<Track> = constraints
+        .into_iter()
+        .map(|(constraint, bounds)| Track::new(total, constraint, bounds))
+        .collect();
+    let used: u32 = tracks.iter().map(|track| track.size).sum();
+
+    if used > total {
+        shrink_tracks(&mut tracks, used - total);
+    } else {
+        let rigid: u32 = tracks
+            .iter()
+            .filter(|track| track.weight == 0)
+            .map(|track| track.size)
+            .sum();
+        let extra = flex_tracks(&mut tracks, total - rigid);
+        if let Some(last) = tracks.last_mut().filter(|_| absorb_leftover) {
+            last.size = last.size.saturating_add(extra).min(last.cap.max(last.size));
         }
     }
 
-    let fill_indices: Vec<usize> = children
+    tracks.iter().map(|track| track.size as u16).collect()
+}
+
+fn shrink_tracks(tracks: &mut [Track], overflow: u32) {
+    let slack: Vec<u32> = tracks
         .iter()
-        .enumerate()
-        .filter_map(|(idx, slot)| matches!(slot.constraint, Constraint::Fill).then_some(idx))
+        .map(|track| track.size.saturating_sub(track.min))
         .collect();
+    let total_slack: u32 = slack.iter().sum();
+    let cuts = if total_slack >= overflow {
+        distribute(overflow, &slack)
+    } else {
+        slack.clone()
+    };
+    for (track, cut) in tracks.iter_mut().zip(&cuts) {
+        track.size -= cut;
+    }
+
+    let overflow = overflow.saturating_sub(total_slack);
+    if overflow > 0 {
+        let sizes: Vec<u32> = tracks.iter().map(|track| track.size).collect();
+        for (track, cut) in tracks.iter_mut().zip(distribute(overflow, &sizes)) {
+            track.size -= cut;
+        }
+    }
+}
+
+fn flex_tracks(tracks: &mut [Track], mut pool: u32) -> u32 {
+    let mut frozen: Vec<bool> = tracks.iter().map(|track| track.weight == 0).collect();
+
+    loop {
+        let weights: Vec<u32> = tracks
+            .iter()
+            .zip(&frozen)
+            .map(|(track, frozen)| if *frozen { 0 } else { track.weight })
+            .collect();
+        if weights.iter().all(|weight| *weight == 0) {
+            return pool;
+        }
+
+        let shares = distribute(pool, &weights);
+        let (mut grow, mut shrink) = (0u32, 0u32);
+        for ((track, share), weight) in tracks.iter().zip(&shares).zip(&weights) {
+            if *weight > 0 {
+                grow += track.min.saturating_sub(*share);
+                shrink += share.saturating_sub(track.max);
+            }
+        }
 
-    if !fill_indices.is_empty() {
-        let share = remaining / fill_indices.len() as u16;
-        let mut extra = remaining % fill_indices.len() as u16;
+        if grow == 0 && shrink == 0 {
+            for ((track, share), weight) in tracks.iter_mut().zip(shares).zip(weights) {
+                if weight > 0 {
+                    track.size = share;
+                }
+            }
+            return 0;
+        }
 
-        for idx in fill_indices {
-            let mut size = share;
-            if extra > 0 {
-                size = size.saturating_add(1);
-                extra -= 1;
+        for (idx, track) in tracks.iter_mut().enumerate() {
+            if weights[idx] == 0 {
+                continue;
+            }
+            let share = shares[idx];
+            let below_min = share < track.min && grow >= shrink;
+            let above_max = share > track.max && shrink >= grow;
+            if below_min || above_max {
+                track.size = share.clamp(track.min, track.max);
+                pool = pool.saturating_sub(track.size);
+                frozen[idx] = true;
             }
-            sizes[idx] = size;
         }
-        remaining = 0;
     }
+}
 
-    if remaining > 0 {
-        if let Some(last) = sizes.last_mut() {
-            *last = last.saturating_add(remaining);
+fn distribute(amount: u32, weights: &[u32]) -> Vec<u32> {
+    let total_weight: u64 = weights.iter().map(|weight| *weight as u64).sum();
+    if total_weight == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let mut shares: Vec<u32> = weights
+        .iter()
+        .map(|weight| (amount as u64 * *weight as u64 / total_weight) as u32)
+        .collect();
+    let mut remaining = amount - shares.iter().sum::<u32>();
+
+    let mut order: Vec<usize> = (0..weights.len()).filter(|idx| weights[*idx] > 0).collect();
+    order.sort_by_key(|idx| {
+        let remainder = amount as u64 * weights[*idx] as u64 % total_weight;
+        std::cmp::Reverse(remainder)
+    });
+    for idx in order {
+        if remaining == 0 {
+            break;
         }
+        shares[idx] += 1;
+        remaining -= 1;
     }
 
-    sizes
+    shares
 }
 
 #[cfg(test)]
@@ -3031,14 +3898,15 @@ mod tests {
     use crate::Frame;
 
     use super::{
-        apply_input_edit, Alignment, Block, BorderType, Borders, Checkbox, CheckboxStyle,
-        Constraint, Direction, FormField, FormFieldStyle, Input, InputEdit, InputStyle, LayoutNode,
-        List, ListStyle, MultiSelect, MultiSelectStyle, Padding, Panel, PanelStyle, Paragraph,
-        ProgressBar, ProgressBarStyle, RadioGroup, RadioGroupStyle, Select, SelectStyle, Slider,
-        SliderStyle, Slot, StatusBar, StatusBarStyle, Stepper, StepperStyle, Switch, SwitchStyle,
-        Table, TableColumn, TableStyle, Tabs, TabsStyle, Text, WrapMode,
+        apply_input_edit, Alignment, Block, BorderType, Borders, Breakpoint, Checkbox,
+        CheckboxStyle, Constraint, CrossAlign, Direction, FormField, FormFieldStyle, GridCell,
+        Input, InputEdit, InputStyle, Justify, LayoutNode, List, ListStyle, Measure, MultiSelect,
+        MultiSelectStyle, Padding, Panel, PanelStyle, Paragraph, ProgressBar, ProgressBarStyle,
+        RadioGroup, RadioGroupStyle, Select, SelectStyle, Slider, SliderStyle, Slot, StatusBar,
+        StatusBarStyle, Stepper, StepperStyle, Switch, SwitchStyle, Table, TableColumn, TableStyle,
+        Tabs, TabsStyle, Text, WrapMode,
     };
-    use crate::{Color, Rect, Style, Theme};
+    use crate::{Color, Rect, Scrollbar, Style, Theme};
 
     #[test]
     fn resolve_mixed_constraints_and_preserve_width() {
@@ -3048,8 +3916,8 @@ mod tests {
             [
                 Slot::new(Constraint::Fixed(10), LayoutNode::leaf("a")),
                 Slot::new(Constraint::Percent(25), LayoutNode::leaf("b")),
-                Slot::new(Constraint::Fill, LayoutNode::leaf("c")),
-                Slot::new(Constraint::Fill, LayoutNode::leaf("d")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("c")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("d")),
             ],
         );
 
@@ -3069,7 +3937,7 @@ mod tests {
             [
                 Slot::new(Constraint::Fixed(15), LayoutNode::leaf("a")),
                 Slot::new(Constraint::Percent(50), LayoutNode::leaf("b")),
-                Slot::new(Constraint::Fill, LayoutNode::leaf("c")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("c")),
             ],
         );
 
@@ -3080,6 +3948,529 @@ mod tests {
         assert_eq!(resolved.area("c"), Some(Rect::new(20, 0, 0, 5)));
     }
 
+    fn widths(total: u16, slots: Vec<Slot>) -> Vec<u16> {
+        let layout = LayoutNode::split("root", Direction::Horizontal, slots);
+        let resolved = layout.resolve(Rect::new(0, 0, total, 1));
+        resolved.zones()[1..]
+            .iter()
+            .map(|zone| zone.area.width)
+            .collect()
+    }
+
+    #[test]
+    fn resolve_bounds_percent_with_min_and_max() {
+        let sidebar = || {
+            Slot::new(Constraint::Percent(30), LayoutNode::leaf("sidebar"))
+                .min(20)
+                .max(50)
+        };
+        let content = || Slot::new(Constraint::Fill(1), LayoutNode::leaf("content"));
+
+        assert_eq!(widths(40, vec![sidebar(), content()]), vec![20, 20]);
+        assert_eq!(widths(100, vec![sidebar(), content()]), vec![30, 70]);
+        assert_eq!(widths(200, vec![sidebar(), content()]), vec![50, 150]);
+    }
+
+    #[test]
+    fn resolve_supports_ratio_and_weighted_fill() {
+        let sizes = widths(
+            90,
+            vec![
+                Slot::new(Constraint::Ratio(1, 3), LayoutNode::leaf("a")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("b")),
+                Slot::new(Constraint::Fill(2), LayoutNode::leaf("c")),
+            ],
+        );
+
+        assert_eq!(sizes, vec![30, 20, 40]);
+    }
+
+    #[test]
+    fn resolve_grows_min_and_caps_max() {
+        let slots = || {
+            vec![
+                Slot::new(Constraint::Min(10), LayoutNode::leaf("a")),
+                Slot::new(Constraint::Max(5), LayoutNode::leaf("b")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("c")),
+            ]
+        };
+
+        assert_eq!(widths(20, slots()), vec![10, 5, 5]);
+        assert_eq!(widths(40, slots()), vec![18, 5, 17]);
+    }
+
+    #[test]
+    fn resolve_freezes_opposing_min_and_max_violations() {
+        let slots = || {
+            vec![
+                Slot::new(Constraint::Fill(10), LayoutNode::leaf("a")).max(5),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("b")).min(15),
+            ]
+        };
+
+        assert_eq!(widths(20, slots()), vec![5, 15]);
+        assert_eq!(widths(30, slots()), vec![5, 25]);
+    }
+
+    #[test]
+    fn resolve_is_order_independent() {
+        let slots = || {
+            vec![
+                Slot::new(Constraint::Fixed(12), LayoutNode::leaf("a")),
+                Slot::new(Constraint::Percent(40), LayoutNode::leaf("b")),
+                Slot::new(Constraint::Fill(3), LayoutNode::leaf("c")).max(9),
+                Slot::new(Constraint::Min(4), LayoutNode::leaf("d")),
+            ]
+        };
+        let forward = widths(50, slots());
+        let mut reversed = widths(50, slots().into_iter().rev().collect());
+        reversed.reverse();
+
+        assert_eq!(forward, reversed);
+        assert_eq!(forward.iter().sum::<u16>(), 50);
+    }
+
+    #[test]
+    fn resolve_shrinks_flexible_slots_before_fixed_ones() {
+        let sizes = widths(
+            20,
+            vec![
+                Slot::new(Constraint::Percent(60), LayoutNode::leaf("a")),
+                Slot::new(Constraint::Fixed(10), LayoutNode::leaf("b")),
+                Slot::new(Constraint::Percent(40), LayoutNode::leaf("c")),
+            ],
+        );
+
+        assert_eq!(sizes, vec![6, 10, 4]);
+
+        let sizes = widths(
+            10,
+            vec![
+                Slot::new(Constraint::Fixed(10), LayoutNode::leaf("a")),
+                Slot::new(Constraint::Fixed(10), LayoutNode::leaf("b")),
+            ],
+        );
+
+        assert_eq!(sizes, vec![5, 5]);
+    }
+
+    fn fixed_row(justify: Justify) -> Vec<Rect> {
+        LayoutNode::split(
+            "root",
+            Direction::Horizontal,
+            [
+                Slot::new(Constraint::Fixed(2), LayoutNode::leaf("a")),
+                Slot::new(Constraint::Fixed(2), LayoutNode::leaf("b")),
+                Slot::new(Constraint::Fixed(2), LayoutNode::leaf("c")),
+            ],
+        )
+        .with_justify(justify)
+        .resolve(Rect::new(0, 0, 18, 1))
+        .zones()[1..]
+            .iter()
+            .map(|zone| zone.area)
+            .collect()
+    }
+
+    #[test]
+    fn resolve_places_gaps_between_slots() {
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Vertical,
+            [
+                Slot::new(Constraint::Fixed(3), LayoutNode::leaf("header")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("body")),
+                Slot::new(Constraint::Fixed(1), LayoutNode::leaf("footer")),
+            ],
+        )
+        .with_gap(1);
+
+        let resolved = layout.resolve(Rect::new(0, 0, 10, 12));
+
+        assert_eq!(resolved.area("header"), Some(Rect::new(0, 0, 10, 3)));
+        assert_eq!(resolved.area("body"), Some(Rect::new(0, 4, 10, 6)));
+        assert_eq!(resolved.area("footer"), Some(Rect::new(0, 11, 10, 1)));
+    }
+
+    #[test]
+    fn resolve_justifies_leftover_space() {
+        let xs = |justify| {
+            fixed_row(justify)
+                .iter()
+                .map(|area| area.x)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(xs(Justify::End), vec![12, 14, 16]);
+        assert_eq!(xs(Justify::Center), vec![6, 8, 10]);
+        assert_eq!(xs(Justify::SpaceBetween), vec![0, 8, 16]);
+        assert_eq!(xs(Justify::SpaceAround), vec![2, 8, 14]);
+    }
+
+    #[test]
+    fn resolve_start_justify_keeps_last_slot_absorbing_leftover() {
+        let areas = fixed_row(Justify::Start);
+
+        assert_eq!(areas[0], Rect::new(0, 0, 2, 1));
+        assert_eq!(areas[2], Rect::new(4, 0, 14, 1));
+    }
+
+    #[test]
+    fn resolve_aligns_slots_on_cross_axis() {
+        let layout = LayoutNode::split(
+            "row",
+            Direction::Horizontal,
+            [
+                Slot::new(Constraint::Fixed(4), LayoutNode::leaf("top")).cross_size(2),
+                Slot::new(Constraint::Fixed(4), LayoutNode::leaf("middle"))
+                    .cross_size(2)
+                    .align(CrossAlign::Center),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("bottom"))
+                    .cross_size(2)
+                    .align(CrossAlign::End),
+                Slot::new(Constraint::Fixed(1), LayoutNode::leaf("tall")).cross_size(40),
+            ],
+        );
+
+        let resolved = layout.resolve(Rect::new(0, 0, 12, 7));
+
+        assert_eq!(resolved.area("top"), Some(Rect::new(0, 0, 4, 2)));
+        assert_eq!(resolved.area("middle"), Some(Rect::new(4, 2, 4, 2)));
+        assert_eq!(resolved.area("bottom"), Some(Rect::new(8, 5, 3, 2)));
+        assert_eq!(resolved.area("tall"), Some(Rect::new(11, 0, 1, 7)));
+    }
+
+    #[test]
+    fn grid_resolves_cells_with_spans_and_gaps() {
+        let layout = LayoutNode::grid(
+            "dashboard",
+            [
+                Constraint::Fixed(3),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ],
+            [
+                Constraint::Percent(25),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ],
+            [
+                GridCell::new(0, 0, LayoutNode::leaf("header")).column_span(3),
+                GridCell::new(1, 0, LayoutNode::leaf("nav")).row_span(2),
+                GridCell::new(1, 1, LayoutNode::leaf("cpu")),
+                GridCell::new(1, 2, LayoutNode::leaf("memory")),
+                GridCell::new(2, 1, LayoutNode::leaf("logs")).column_span(2),
+            ],
+        )
+        .with_row_gap(1)
+        .with_column_gap(2);
+
+        let resolved = layout.resolve(Rect::new(0, 0, 44, 13));
+
+        assert_eq!(resolved.area("dashboard"), Some(Rect::new(0, 0, 44, 13)));
+        assert_eq!(resolved.area("header"), Some(Rect::new(0, 0, 44, 3)));
+        assert_eq!(resolved.area("nav"), Some(Rect::new(0, 4, 10, 9)));
+        assert_eq!(resolved.area("cpu"), Some(Rect::new(12, 4, 15, 4)));
+        assert_eq!(resolved.area("memory"), Some(Rect::new(29, 4, 15, 4)));
+        assert_eq!(resolved.area("logs"), Some(Rect::new(12, 9, 32, 4)));
+    }
+
+    #[test]
+    fn grid_clamps_spans_and_out_of_range_cells() {
+        let layout = LayoutNode::grid(
+            "grid",
+            [Constraint::Fill(1), Constraint::Fill(1)],
+            [Constraint::Fill(1), Constraint::Fill(1)],
+            [
+                GridCell::new(1, 1, LayoutNode::leaf("corner"))
+                    .row_span(5)
+                    .column_span(5),
+                GridCell::new(4, 0, LayoutNode::leaf("missing")),
+            ],
+        );
+
+        let resolved = layout.resolve(Rect::new(0, 0, 10, 6));
+
+        assert_eq!(resolved.area("corner"), Some(Rect::new(5, 3, 5, 3)));
+        assert_eq!(resolved.area("missing"), Some(Rect::new(0, 6, 5, 0)));
+    }
+
+    #[test]
+    fn widgets_measure_their_content() {
+        let paragraph = Paragraph::new("one two three four").padding(Padding::all(1));
+        assert_eq!(paragraph.preferred_width(), 20);
+        assert_eq!(paragraph.measure_height(11), 5);
+        assert_eq!(paragraph.measure_height(40), 3);
+
+        let select = Select::new(["dev", "production"]).placeholder("pick");
+        assert_eq!(select.preferred_width(), 13);
+        assert_eq!(select.measure_height(20), 1);
+        assert_eq!(select.expanded(true).measure_height(20), 3);
+
+        assert_eq!(Text::new("ab\ncdef").measure_height(1), 2);
+        assert_eq!(List::new(["a", "bcd"]).preferred_width(), 5);
+        assert_eq!(
+            RadioGroup::new(["a", "b", "c"])
+                .max_visible(2)
+                .measure_height(9),
+            2
+        );
+        assert_eq!(MultiSelect::new(["ab"]).preferred_width(), 7);
+        assert_eq!(StatusBar::new().left("ab").right("cd").preferred_width(), 5);
+    }
+
+    #[test]
+    fn resolve_measured_sizes_content_slots() {
+        let help = Paragraph::new("aaaa bbbb cccc dddd");
+        let env = Select::new(["staging", "prod"]);
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Vertical,
+            [
+                Slot::new(
+                    Constraint::Content,
+                    LayoutNode::split(
+                        "top",
+                        Direction::Horizontal,
+                        [
+                            Slot::new(Constraint::Content, LayoutNode::leaf("env")),
+                            Slot::new(Constraint::Fill(1), LayoutNode::leaf("search")),
+                        ],
+                    ),
+                )
+                .max(1),
+                Slot::new(
+                    Constraint::Content,
+                    LayoutNode::leaf("help").with_padding(Padding::symmetric(0, 1)),
+                ),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("body")),
+            ],
+        );
+
+        let resolved =
+            layout.resolve_measured(Rect::new(0, 0, 11, 12), &[("env", &env), ("help", &help)]);
+
+        assert_eq!(resolved.area("top"), Some(Rect::new(0, 0, 11, 1)));
+        assert_eq!(resolved.area("env"), Some(Rect::new(0, 0, 10, 1)));
+        assert_eq!(resolved.area("search"), Some(Rect::new(10, 0, 1, 1)));
+        assert_eq!(resolved.area("help"), Some(Rect::new(1, 1, 9, 2)));
+        assert_eq!(resolved.area("body"), Some(Rect::new(0, 3, 11, 9)));
+
+        let unmeasured = layout.resolve(Rect::new(0, 0, 11, 12));
+        assert_eq!(unmeasured.area("body").map(|area| area.height), Some(5));
+    }
+
+    #[test]
+    fn grid_content_tracks_use_cell_measurements() {
+        let label = Text::new("label:");
+        let notes = Paragraph::new("one two three");
+        let layout = LayoutNode::grid(
+            "form",
+            [Constraint::Content, Constraint::Fill(1)],
+            [Constraint::Content, Constraint::Fill(1)],
+            [
+                GridCell::new(0, 0, LayoutNode::leaf("label")),
+                GridCell::new(0, 1, LayoutNode::leaf("notes")),
+                GridCell::new(1, 0, LayoutNode::leaf("footer")).column_span(2),
+            ],
+        );
+
+        let resolved = layout.resolve_measured(
+            Rect::new(0, 0, 14, 10),
+            &[("label", &label), ("notes", &notes)],
+        );
+
+        assert_eq!(resolved.area("label"), Some(Rect::new(0, 0, 6, 2)));
+        assert_eq!(resolved.area("notes"), Some(Rect::new(6, 0, 8, 2)));
+        assert_eq!(resolved.area("footer"), Some(Rect::new(0, 2, 14, 8)));
+    }
+
+    fn duplicate_bodies() -> LayoutNode {
+        LayoutNode::split(
+            "main",
+            Direction::Horizontal,
+            [
+                Slot::new(
+                    Constraint::Fixed(10),
+                    LayoutNode::split(
+                        "sidebar",
+                        Direction::Vertical,
+                        [
+                            Slot::new(Constraint::Fixed(1), LayoutNode::leaf("title")),
+                            Slot::new(Constraint::Fill(1), LayoutNode::leaf("body")),
+                        ],
+                    ),
+                ),
+                Slot::new(
+                    Constraint::Fill(1),
+                    LayoutNode::split(
+                        "content",
+                        Direction::Vertical,
+                        [Slot::new(Constraint::Fill(1), LayoutNode::leaf("body"))],
+                    ),
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn resolved_layout_looks_up_zones_by_path() {
+        let resolved = duplicate_bodies().resolve(Rect::new(0, 0, 30, 8));
+
+        assert_eq!(
+            resolved.area("main/sidebar/body"),
+            Some(Rect::new(0, 1, 10, 7))
+        );
+        assert_eq!(resolved.area("content/body"), Some(Rect::new(10, 0, 20, 8)));
+        assert_eq!(resolved.area("body"), Some(Rect::new(10, 0, 20, 8)));
+        assert_eq!(
+            resolved
+                .zone("sidebar/title")
+                .map(|zone| zone.path.as_str()),
+            Some("main/sidebar/title")
+        );
+        assert_eq!(resolved.area("bar/body"), None);
+        assert_eq!(resolved.area("missing"), None);
+    }
+
+    #[test]
+    fn resolved_layout_prefers_paths_over_nested_names() {
+        let layout = LayoutNode::split(
+            "x",
+            Direction::Vertical,
+            [
+                Slot::new(Constraint::Fixed(2), LayoutNode::leaf("header")),
+                Slot::new(Constraint::Fill(1), LayoutNode::leaf("x")),
+            ],
+        );
+        let resolved = layout.resolve(Rect::new(0, 0, 10, 6));
+
+        assert_eq!(resolved.zone("x").map(|zone| zone.path.as_str()), Some("x"));
+        assert_eq!(resolved.area("x"), Some(Rect::new(0, 0, 10, 6)));
+        assert_eq!(resolved.area("x/x"), Some(Rect::new(0, 2, 10, 4)));
+    }
+
+    #[test]
+    fn resolved_layout_iterates_subtree() {
+        let resolved = duplicate_bodies().resolve(Rect::new(0, 0, 30, 8));
+
+        let names: Vec<_> = resolved
+            .subtree("sidebar")
+            .map(|zone| zone.path.as_str())
+            .collect();
+
+        assert_eq!(
+            names,
+            ["main/sidebar", "main/sidebar/title", "main/sidebar/body"]
+        );
+        assert_eq!(resolved.subtree("missing").count(), 0);
+    }
+
+    #[test]
+    fn resolved_layout_validate_reports_duplicate_and_empty_names() {
+        let resolved = duplicate_bodies().resolve(Rect::new(0, 0, 30, 8));
+        let err = resolved
+            .validate()
+            .expect_err("duplicates should be reported");
+        assert_eq!(
+            err.to_string(),
+            "duplicate zone name `body` at `main/sidebar/body`, `main/content/body`"
+        );
+
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Vertical,
+            [Slot::new(Constraint::Fill(1), LayoutNode::leaf(""))],
+        );
+        let err = layout
+            .resolve(Rect::new(0, 0, 4, 4))
+            .validate()
+            .expect_err("empty names should be reported");
+        assert_eq!(err.to_string(), "empty zone name at `root/`");
+
+        let layout = LayoutNode::split(
+            "root",
+            Direction::Vertical,
+            [Slot::new(Constraint::Fill(1), LayoutNode::leaf("a/b"))],
+        );
+        let err = layout
+            .resolve(Rect::new(0, 0, 4, 4))
+            .validate()
+            .expect_err("slashes should be reported");
+        assert_eq!(
+            err.to_string(),
+            "zone name `a/b` at `root/a/b` contains `/`"
+        );
+
+        assert!(responsive_console()
+            .resolve(Rect::new(0, 0, 120, 40))
+            .validate()
+            .is_ok());
+    }
+
+    fn responsive_console() -> LayoutNode {
+        LayoutNode::responsive(
+            "body",
+            [
+                Breakpoint::new(LayoutNode::split(
+                    "wide",
+                    Direction::Horizontal,
+                    [
+                        Slot::new(Constraint::Fixed(20), LayoutNode::leaf("sidebar")),
+                        Slot::new(Constraint::Fill(1), LayoutNode::leaf("content")),
+                    ],
+                ))
+                .min_width(100),
+                Breakpoint::new(LayoutNode::split(
+                    "narrow",
+                    Direction::Vertical,
+                    [
+                        Slot::new(Constraint::Fixed(1), LayoutNode::leaf("tabs")),
+                        Slot::new(Constraint::Fill(1), LayoutNode::leaf("content")),
+                    ],
+                )),
+            ],
+        )
+    }
+
+    #[test]
+    fn responsive_picks_first_variant_that_fits() {
+        let layout = responsive_console();
+
+        let wide = layout.resolve(Rect::new(0, 0, 120, 40));
+        assert_eq!(wide.area("sidebar"), Some(Rect::new(0, 0, 20, 40)));
+        assert_eq!(wide.area("content"), Some(Rect::new(20, 0, 100, 40)));
+        assert_eq!(wide.hidden(), ["narrow", "tabs"]);
+
+        let narrow = layout.resolve(Rect::new(0, 0, 80, 24));
+        assert_eq!(narrow.area("tabs"), Some(Rect::new(0, 0, 80, 1)));
+        assert_eq!(narrow.area("content"), Some(Rect::new(0, 1, 80, 23)));
+        assert_eq!(narrow.area("sidebar"), None);
+        assert!(narrow.is_hidden("sidebar"));
+        assert!(!narrow.is_hidden("content"));
+        assert!(!narrow.is_hidden("unknown"));
+    }
+
+    #[test]
+    fn responsive_falls_back_to_last_variant_and_checks_height() {
+        let layout = LayoutNode::responsive(
+            "root",
+            [
+                Breakpoint::new(LayoutNode::leaf("tall"))
+                    .min_width(10)
+                    .min_height(20),
+                Breakpoint::new(LayoutNode::leaf("short")).min_width(50),
+            ],
+        )
+        .with_padding(Padding::all(1));
+
+        let resolved = layout.resolve(Rect::new(0, 0, 40, 12));
+
+        assert_eq!(resolved.area("root"), Some(Rect::new(1, 1, 38, 10)));
+        assert_eq!(resolved.area("short"), Some(Rect::new(1, 1, 38, 10)));
+        assert!(resolved.is_hidden("tall"));
+    }
+
     #[test]
     fn padding_applies_safely_with_saturation() {
         let area = Rect::new(0, 0, 1, 1);
@@ -3094,7 +4485,7 @@ mod tests {
             "root",
             Direction::Vertical,
             [Slot::new(
-                Constraint::Fill,
+                Constraint::Fill(1),
                 LayoutNode::leaf("content").with_padding(Padding::symmetric(1, 2)),
             )],
         );
@@ -3206,6 +4597,27 @@ mod tests {
         assert_eq!(frame.char_at(2, 2), Some('f'));
     }
 
+    #[test]
+    fn list_scrollbar_tracks_position_only_on_overflow() {
+        let mut frame = Frame::new(8, 3);
+        let items = ["zero", "one", "two", "three", "four", "five"];
+        List::new(items)
+            .selected(4)
+            .scrollbar(Scrollbar::default())
+            .render(&mut frame, Rect::new(0, 0, 8, 3));
+
+        assert_eq!(frame.char_at(7, 0), Some('│'));
+        assert_eq!(frame.char_at(7, 1), Some('█'));
+        assert_eq!(frame.char_at(7, 2), Some('│'));
+        assert_eq!(frame.char_at(2, 2), Some('f'));
+
+        frame.clear();
+        List::new(["one", "two"])
+            .scrollbar(Scrollbar::default())
+            .render(&mut frame, Rect::new(0, 0, 8, 3));
+        assert_eq!(frame.char_at(7, 0), Some(' '));
+    }
+
     #[test]
     fn list_empty_is_noop() {
         let mut frame = Frame::new(4, 2);
@@ -3306,6 +4718,43 @@ mod tests {
         assert_eq!(frame.char_at(0, 0), Some('a'));
     }
 
+    #[test]
+    fn input_places_frame_cursor_only_when_focused() {
+        let mut frame = Frame::new(12, 3);
+        let area = Rect::new(2, 1, 8, 1);
+
+        Input::new().value("abc").cursor(2).render(&mut frame, area);
+        assert_eq!(frame.cursor(), None);
+
+        Input::new()
+            .value("abc")
+            .cursor(2)
+            .focused(true)
+            .render(&mut frame, area);
+        assert_eq!(
+            frame.cursor().map(|cursor| (cursor.x, cursor.y)),
+            Some((4, 1))
+        );
+        assert_eq!(frame.char_at(4, 1), Some('c'));
+        assert_eq!(frame.style_at(4, 1), Some(Style::default()));
+    }
+
+    #[test]
+    fn input_cursor_style_is_an_opt_in_highlight() {
+        let mut frame = Frame::new(6, 1);
+        let highlight = Style::new().bg(Color::Ansi(45));
+        Input::new()
+            .value("abc")
+            .cursor(1)
+            .focused(true)
+            .cursor_style(highlight)
+            .render(&mut frame, Rect::new(0, 0, 6, 1));
+
+        assert_eq!(frame.char_at(1, 0), Some('b'));
+        assert_eq!(frame.style_at(1, 0), Some(highlight));
+        assert_eq!(frame.style_at(0, 0), Some(Style::default()));
+    }
+
     #[test]
     fn paragraph_char_and_no_wrap_modes_render_differently() {
         let mut frame = Frame::new(5, 2);
@@ -3392,6 +4841,40 @@ mod tests {
         assert_eq!(frame.char_at(11, 2), Some('c'));
     }
 
+    #[test]
+    fn table_sizes_content_columns_to_widest_cell() {
+        let mut frame = Frame::new(12, 4);
+        let columns = vec![
+            TableColumn::new("ID", Constraint::Content),
+            TableColumn::new("Name", Constraint::Fill(1)),
+        ];
+        let rows = vec![
+            vec!["alpha".into(), "x".into()],
+            vec!["b".into(), "y".into()],
+        ];
+        Table::new(columns, rows).render(&mut frame, Rect::new(0, 0, 12, 4));
+
+        assert_eq!(frame.char_at(5, 0), Some('N'));
+        assert_eq!(frame.char_at(5, 2), Some('x'));
+    }
+
+    #[test]
+    fn table_scrollbar_spans_body_rows() {
+        let mut frame = Frame::new(10, 5);
+        let columns = vec![TableColumn::new("Name", Constraint::Fill(1))];
+        let rows = (0..6).map(|idx| vec![format!("row{idx}")]).collect();
+        Table::new(columns, rows)
+            .selected(0)
+            .scrollbar(Scrollbar::default())
+            .render(&mut frame, Rect::new(0, 0, 10, 5));
+
+        assert_eq!(frame.char_at(9, 1), Some('─'));
+        assert_eq!(frame.char_at(9, 2), Some('█'));
+        assert_eq!(frame.char_at(9, 3), Some('│'));
+        assert_eq!(frame.char_at(9, 4), Some('│'));
+        assert_eq!(frame.char_at(0, 2), Some('r'));
+    }
+
     #[test]
     fn form_field_renders_label_and_help() {
         let mut frame = Frame::new(20, 4);
@@ -3507,6 +4990,18 @@ mod tests {
         assert_eq!(frame.char_at(4, 1), Some('4'));
     }
 
+    #[test]
+    fn radio_group_scrollbar_follows_highlight() {
+        let mut frame = Frame::new(12, 2);
+        RadioGroup::new(["v0", "v1", "v2", "v3"])
+            .highlighted(3)
+            .scrollbar(Scrollbar::default())
+            .render(&mut frame, Rect::new(0, 0, 12, 2));
+
+        assert_eq!(frame.char_at(11, 0), Some('│'));
+        assert_eq!(frame.char_at(11, 1), Some('█'));
+    }
+
     #[test]
     fn slider_places_thumb_from_value() {
         let mut frame = Frame::new(10, 1);
@@ -3638,6 +5133,21 @@ mod tests {
         assert_eq!(frame.style_at(0, 1), Some(highlight));
     }
 
+    #[test]
+    fn multiselect_scrollbar_uses_visible_rows_and_glyphs() {
+        let mut frame = Frame::new(10, 4);
+        MultiSelect::new(["a", "b", "c", "d", "e"])
+            .highlighted(4)
+            .focused(true)
+            .max_visible(2)
+            .scrollbar(Scrollbar::default().track_symbol('.').thumb_symbol('#'))
+            .render(&mut frame, Rect::new(0, 0, 10, 4));
+
+        assert_eq!(frame.char_at(9, 0), Some('.'));
+        assert_eq!(frame.char_at(9, 1), Some('#'));
+        assert_eq!(frame.char_at(9, 2), Some(' '));
+    }
+
     #[test]
     fn advanced_style_bundles_use_theme_tokens() {
         let theme = Theme::from_json_str(